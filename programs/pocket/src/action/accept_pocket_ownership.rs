@@ -0,0 +1,64 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptPocketOwnershipContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress,
+        constraint = pocket.pending_owner == Some(signer.key()) @ PocketError::OnlyPendingOwner,
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    /// CHECK: the index of the previous owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    // The pocket is moved into the index of the new owner
+    #[account(
+        init_if_needed,
+        seeds = [POCKET_OWNER_INDEX_SEED, signer.key().as_ref()],
+        payer = signer,
+        space = PocketOwnerIndex::space(0),
+        bump
+    )]
+    pub new_pocket_owner_index: Account<'info, PocketOwnerIndex>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptPocketOwnershipContext<'info> {
+    pub fn execute(&mut self, new_index_bump: u8) -> Result<()> {
+        let pocket = &mut self.pocket;
+        let previous_owner = pocket.owner;
+
+        pocket.transfer_ownership(self.signer.key());
+
+        unindex_pocket(&self.pocket_owner_index, pocket.key()).unwrap();
+        index_pocket(
+            &mut self.new_pocket_owner_index,
+            self.signer.key(),
+            new_index_bump,
+            pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        pocket_emit!(
+            PocketOwnerTransferred {
+                pocket_address: pocket.key(),
+                previous_owner,
+                owner: pocket.owner
+            }
+        );
+
+        Ok(())
+    }
+}
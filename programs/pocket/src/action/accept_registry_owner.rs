@@ -0,0 +1,41 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from the pending owner.
+#[derive(Accounts)]
+pub struct AcceptRegistryOwnerContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.pending_owner == Some(signer.key()) @ PocketError::OnlyPendingOwner
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> AcceptRegistryOwnerContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        let pocket_registry = &mut self.pocket_registry;
+        let previous_owner = pocket_registry.owner;
+
+        pocket_registry.owner = self.signer.key();
+        pocket_registry.pending_owner = None;
+
+        pocket_emit!(
+            RegistryOwnerTransferred {
+                previous_owner,
+                owner: pocket_registry.owner
+            }
+        );
+
+        Ok(())
+    }
+}
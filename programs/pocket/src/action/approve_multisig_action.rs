@@ -0,0 +1,46 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from a multisig signer.
+#[derive(Accounts)]
+pub struct ApproveMultisigActionContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_multisig_signer(signer.key()) @ PocketError::OnlyMultisigSigner
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig_proposal.id.to_le_bytes().as_ref()],
+        bump = multisig_proposal.bump,
+    )]
+    pub multisig_proposal: Account<'info, MultisigProposal>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> ApproveMultisigActionContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        let multisig_proposal = &mut self.multisig_proposal;
+
+        multisig_proposal.approve(self.signer.key()).unwrap();
+
+        pocket_emit!(
+            MultisigActionApproved {
+                actor: self.signer.key(),
+                proposal_id: multisig_proposal.id,
+                approvals: multisig_proposal.approvals.clone()
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from the operator.
+// The waiting pocket is started once the best price of the market fulfills its start condition.
+#[derive(Accounts)]
+pub struct CheckStartConditionContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    /// CHECK: the index of the owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    /// CHECK: the market was verified when the pocket was created
+    #[account(address = pocket.market_key)]
+    pub market_key: AccountInfo<'info>,
+
+    /// CHECK: the order book is verified against the market state
+    pub market_bids: AccountInfo<'info>,
+
+    /// CHECK: the order book is verified against the market state
+    pub market_asks: AccountInfo<'info>,
+}
+
+impl<'info> CheckStartConditionContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        // Only allow operator to check the start condition
+        if !self.pocket_registry.is_operator(self.signer.key()) {
+            return Err(PocketError::OnlyOperator.into());
+        }
+
+        assert_eq!(self.pocket.status, PocketStatus::Waiting, "POCKET_IS_NOT_WAITING");
+
+        // The start condition is compared with the amount received for a batch, as the buy condition
+        let quoted_amount = load_best_price_quote(
+            &self.market_key,
+            self.market_key.owner,
+            &self.market_bids,
+            &self.market_asks,
+            self.pocket.side,
+            self.pocket.batch_volume,
+        ).unwrap();
+
+        if quoted_amount.is_none() || !self.pocket.is_able_to_start(quoted_amount.unwrap()) {
+            msg!("ERROR::POCKET::START_CONDITION_NOT_REACHED");
+            return Err(PocketError::StartConditionNotReached.into());
+        }
+
+        let pocket = &mut self.pocket;
+        pocket.start(Clock::get().unwrap().unix_timestamp as u64);
+
+        pocket_emit!(
+            PocketUpdated {
+                actor: self.signer.key(),
+                pocket_address: pocket.key(),
+                status: pocket.status,
+                memo: String::from("START_CONDITION_REACHED")
+            }
+        );
+
+        sync_indexed_pocket_status(&self.pocket_owner_index, self.pocket.key(), self.pocket.status).unwrap();
+
+        Ok(())
+    }
+}
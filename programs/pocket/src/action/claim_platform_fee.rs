@@ -0,0 +1,69 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClaimPlatformFeeContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_fee_manager(signer.key()) @ PocketError::OnlyFeeManager
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, platform_treasury_vault.mint.as_ref()],
+        bump
+    )]
+    pub platform_treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == platform_treasury_vault.mint @ PocketError::InvalidTokenAccount
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimPlatformFeeContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        let pocket_registry = &self.pocket_registry;
+        let amount = self.platform_treasury_vault.amount;
+
+        // find the bump to sign with the pda
+        let bump = &[pocket_registry.bump][..];
+        let signer = &[&[PLATFORM_SEED, bump][..]];
+
+        // transfer the accumulated fee
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.platform_treasury_vault.to_account_info(),
+                    to: self.recipient_token_account.to_account_info(),
+                    authority: pocket_registry.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        ).unwrap();
+
+        // emit event
+        pocket_emit!(
+            PlatformFeeClaimed {
+                actor: self.signer.key(),
+                mint_address: self.platform_treasury_vault.mint,
+                recipient: self.recipient_token_account.key(),
+                amount
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -19,6 +19,14 @@ pub struct ClosePocketAccountsContext<'info> {
     )]
     pub pocket: Account<'info, Pocket>,
 
+    /// CHECK: the index of the owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -33,9 +41,8 @@ impl<'info> ClosePocketAccountsContext<'info> {
     pub fn execute(&mut self) -> Result<()> {
         let pocket = &self.pocket;
 
-        // find the bump to sign with the pda
-        let bump = &[pocket.bump][..];
-        let signer = &[&[POCKET_SEED, pocket.id.as_bytes().as_ref(), bump][..]];
+        // find the seeds to sign with the pda
+        pocket_signer!(pocket, signer);
 
         // close base token vault
         token::close_account(
@@ -63,6 +70,9 @@ impl<'info> ClosePocketAccountsContext<'info> {
             )
         ).unwrap();
 
+        // remove the pocket from the owner index
+        unindex_pocket(&self.pocket_owner_index, pocket.key()).unwrap();
+
         // return result
         Ok(())
     }
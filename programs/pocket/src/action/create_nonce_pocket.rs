@@ -0,0 +1,84 @@
+use crate::*;
+
+// Define the context of a pocket addressed by its owner and the next nonce of the owner index,
+// so that nobody else can take the address. The id is only kept as a label.
+#[derive(Accounts)]
+#[instruction(params: CreatePocketParams)]
+pub struct CreateNoncePocketContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [POCKET_OWNER_INDEX_SEED, signer.key().as_ref()],
+        payer = signer,
+        space = PocketOwnerIndex::space(0),
+        bump
+    )]
+    pub pocket_owner_index: Account<'info, PocketOwnerIndex>,
+
+    #[account(
+        init,
+        seeds = [POCKET_NONCE_SEED, signer.key().as_ref(), pocket_owner_index.next_nonce.to_le_bytes().as_ref()],
+        payer = signer,
+        space = Pocket::space(params.id.len(), params.name.len(), params.stop_conditions.len(), 0),
+        bump
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    /// CHECK: the market state is verified against the whitelisted market
+    #[account(address = params.market_key)]
+    pub market_key: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = sysvar::rent::ID)]
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateNoncePocketContext<'info> {
+    pub fn execute(&mut self, params: CreatePocketParams, pocket_bump: u8, index_bump: u8) -> Result<()> {
+        // Only whitelisted mint accounts and market of the token pair can be traded
+        validate_pocket_market(&self.pocket_registry, &self.market_key, &params).unwrap();
+
+        // Reserve lamports for operator tips
+        reserve_keeper_tip(
+            &self.system_program.to_account_info(),
+            &self.signer.to_account_info(),
+            &self.pocket.to_account_info(),
+            params.keeper_tip_deposit,
+        ).unwrap();
+
+        // Update pocket state, the nonce is consumed by the pocket
+        let address_seed = PocketAddressSeed::OwnerNonce {
+            owner: self.signer.key(),
+            nonce: self.pocket_owner_index.take_nonce(),
+        };
+
+        initialize_pocket(&mut self.pocket, self.signer.key(), params, address_seed, pocket_bump).unwrap();
+
+        // List the pocket in the owner index
+        index_pocket(
+            &mut self.pocket_owner_index,
+            self.signer.key(),
+            index_bump,
+            &self.pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        // Return instruction result
+        Ok(())
+    }
+}
@@ -28,11 +28,29 @@ pub struct CreatePocketParams {
     // Define the stop condition
     pub stop_conditions: Vec<StopCondition>,
 
-    // Define the frequency
+    // Define the frequency, from minutes to calendar units
     pub frequency: DateDuration,
 
     // Define the trade side
-    pub side: TradeSide
+    pub side: TradeSide,
+
+    // Define the tip in lamports paid to the operator for every executed batch, zero to use the platform default
+    pub keeper_tip_amount: u64,
+
+    // Define the lamports reserved for operator tips
+    pub keeper_tip_deposit: u64,
+
+    // Define whether the schedule follows the actual execution time or the start time, relative by default
+    pub schedule_mode: Option<ScheduleMode>,
+
+    // Define how the missed executions are handled, skipped by default
+    pub catch_up_policy: Option<CatchUpPolicy>,
+
+    // Define the UTC time ranges in which the pocket can be executed, at any time by default
+    pub execution_windows: Option<Vec<ExecutionWindow>>,
+
+    // Define the price condition which starts the pocket, the pocket waits for it when set
+    pub start_condition: Option<PriceCondition>,
 }
 
 #[derive(Accounts)]
@@ -40,9 +58,9 @@ pub struct CreatePocketParams {
 pub struct CreatePocketContext<'info> {
     #[account(
         init,
-        seeds = [POCKET_SEED, params.id.as_bytes().as_ref()],
+        seeds = [POCKET_SEED, signer.key().as_ref(), params.id.as_bytes().as_ref()],
         payer = signer,
-        space = 10240,
+        space = Pocket::space(params.id.len(), params.name.len(), params.stop_conditions.len(), 0),
         bump
     )]
     pub pocket: Account<'info, Pocket>,
@@ -50,6 +68,26 @@ pub struct CreatePocketContext<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        init_if_needed,
+        seeds = [POCKET_OWNER_INDEX_SEED, signer.key().as_ref()],
+        payer = signer,
+        space = PocketOwnerIndex::space(0),
+        bump
+    )]
+    pub pocket_owner_index: Account<'info, PocketOwnerIndex>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    /// CHECK: the market state is verified against the whitelisted market
+    #[account(address = params.market_key)]
+    pub market_key: AccountInfo<'info>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -61,46 +99,144 @@ pub struct CreatePocketContext<'info> {
 }
 
 impl<'info> CreatePocketContext<'info> {
-    pub fn execute(&mut self, params: CreatePocketParams, pocket_bump: u8) -> Result<()> {
+    pub fn execute(&mut self, params: CreatePocketParams, pocket_bump: u8, index_bump: u8) -> Result<()> {
+        // Only whitelisted mint accounts and market of the token pair can be traded
+        validate_pocket_market(&self.pocket_registry, &self.market_key, &params).unwrap();
+
+        // Reserve lamports for operator tips
+        reserve_keeper_tip(
+            &self.system_program.to_account_info(),
+            &self.signer.to_account_info(),
+            &self.pocket.to_account_info(),
+            params.keeper_tip_deposit,
+        ).unwrap();
+
         // Update pocket state
-        self.initialize_pocket(params, pocket_bump).unwrap();
+        initialize_pocket(&mut self.pocket, self.signer.key(), params, PocketAddressSeed::OwnerId { owner: self.signer.key() }, pocket_bump).unwrap();
+
+        // List the pocket in the owner index
+        index_pocket(
+            &mut self.pocket_owner_index,
+            self.signer.key(),
+            index_bump,
+            &self.pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
 
         // Return instruction result
         Ok(())
     }
+}
 
-    fn initialize_pocket(&mut self, params: CreatePocketParams, pocket_bump: u8) -> Result<()> {
-        // propagate data
-        self.pocket.id = params.id;
-        self.pocket.start_at = params.start_at;
-        self.pocket.name = params.name;
-        self.pocket.base_token_mint_address = params.base_token_address;
-        self.pocket.quote_token_mint_address = params.quote_token_address;
-        self.pocket.batch_volume = params.batch_volume;
-        self.pocket.buy_condition = params.buy_condition;
-        self.pocket.stop_conditions = params.stop_conditions;
-        self.pocket.frequency = params.frequency;
-        self.pocket.side = params.side;
-        self.pocket.market_key = params.market_key;
-
-        // assign default values
-        self.pocket.bump = pocket_bump;
-        self.pocket.owner = self.signer.key();
-        self.pocket.status = PocketStatus::Active;
-
-        // must check for valid data
-        let pocket = self.pocket.clone();
-        pocket.validate_pocket_data().unwrap();
-
-        // emit event
-        pocket_emit!(
-          PocketCreated {
-                pocket_address: pocket.key().clone(),
-                owner: pocket.owner.clone(),
-                name: pocket.name.clone()
-            }
-        );
+// Check the token pair and the market of a new pocket against the registry, returns the whitelisted market
+pub fn validate_pocket_market(
+    pocket_registry: &PocketPlatformRegistry,
+    market_key: &AccountInfo,
+    params: &CreatePocketParams,
+) -> Result<MarketInfo> {
+    if !pocket_registry.is_mint_account_enabled(params.base_token_address)
+        || !pocket_registry.is_mint_account_enabled(params.quote_token_address) {
+        return Err(PocketError::MintAccountNotAllowed.into());
+    }
 
-        Ok(())
+    let market_info = match pocket_registry.get_market_info(params.market_key) {
+        Some(market_info) if market_info.is_enabled => market_info.clone(),
+        _ => return Err(PocketError::MarketNotAllowed.into()),
+    };
+
+    if market_info.base_mint_address != params.base_token_address
+        || market_info.quote_mint_address != params.quote_token_address {
+        return Err(PocketError::MarketMintMismatched.into());
+    }
+
+    // Read the token pair from the market state itself
+    if *market_key.owner != market_info.dex_program {
+        return Err(PocketError::MarketNotAllowed.into());
+    }
+
+    let (coin_mint, pc_mint) = load_market_mints(
+        market_key,
+        &market_info.dex_program,
+    ).unwrap();
+
+    if coin_mint != params.base_token_address || pc_mint != params.quote_token_address {
+        return Err(PocketError::MarketMintMismatched.into());
     }
-}
\ No newline at end of file
+
+    Ok(market_info)
+}
+
+// Move the lamports reserved for operator tips into the pocket
+pub fn reserve_keeper_tip<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    pocket: &AccountInfo<'info>,
+    keeper_tip_deposit: u64,
+) -> Result<()> {
+    if keeper_tip_deposit > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: from.clone(),
+                    to: pocket.clone(),
+                },
+            ),
+            keeper_tip_deposit,
+        ).unwrap();
+    }
+
+    Ok(())
+}
+
+// Fill in the state of a newly created pocket
+pub fn initialize_pocket(
+    pocket: &mut Account<Pocket>,
+    owner: Pubkey,
+    params: CreatePocketParams,
+    address_seed: PocketAddressSeed,
+    pocket_bump: u8,
+) -> Result<()> {
+    // propagate data
+    pocket.id = params.id;
+    pocket.start_at = params.start_at;
+    pocket.name = params.name;
+    pocket.base_token_mint_address = params.base_token_address;
+    pocket.quote_token_mint_address = params.quote_token_address;
+    pocket.batch_volume = params.batch_volume;
+    pocket.buy_condition = params.buy_condition;
+    pocket.stop_conditions = params.stop_conditions;
+    pocket.frequency = Some(params.frequency);
+    pocket.side = params.side;
+    pocket.market_key = params.market_key;
+    pocket.keeper_tip_amount = params.keeper_tip_amount;
+    pocket.keeper_tip_balance = params.keeper_tip_deposit;
+    pocket.schedule_mode = params.schedule_mode.unwrap_or_default();
+    pocket.catch_up_policy = params.catch_up_policy.unwrap_or_default();
+    pocket.execution_windows = params.execution_windows.unwrap_or_default();
+    pocket.start_condition = params.start_condition;
+
+    // assign default values
+    pocket.bump = pocket_bump;
+    pocket.owner = owner;
+    pocket.status = if pocket.start_condition.is_some() { PocketStatus::Waiting } else { PocketStatus::Active };
+    pocket.version = POCKET_VERSION;
+    pocket.address_seed = address_seed;
+
+    // must check for valid data
+    pocket.validate_id_format().unwrap();
+    pocket.validate_pocket_data().unwrap();
+    pocket.validate_start_at().unwrap();
+
+    // emit event
+    pocket_emit!(
+      PocketCreated {
+            pocket_address: pocket.key().clone(),
+            owner: pocket.owner.clone(),
+            name: pocket.name.clone()
+        }
+    );
+
+    Ok(())
+}
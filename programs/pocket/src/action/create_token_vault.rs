@@ -6,12 +6,21 @@ pub struct CreateTokenVaultContext<'info> {
     pub signer: Signer<'info>,
 
     #[account(
-        seeds = [POCKET_SEED, pocket.id.as_bytes().as_ref()],
-        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner,
-        bump = pocket.bump,
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress,
+        constraint = pocket.has_permission(signer.key(), DelegatePermission::Deposit) @ PocketError::OnlyOwnerOrDelegate,
     )]
     pub pocket: Account<'info, Pocket>,
 
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        constraint = pocket_registry.is_mint_account_enabled(mint_account.key()) @ PocketError::MintAccountNotAllowed
+    )]
     pub mint_account: Account<'info, Mint>,
 
     #[account(init,
@@ -0,0 +1,62 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateTreasuryVaultContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_fee_manager(signer.key()) @ PocketError::OnlyFeeManager
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        constraint = pocket_registry.is_mint_account_existed(mint_account.key()) @ PocketError::MintAccountNotExisted
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(init,
+        token::mint = mint_account,
+        token::authority = pocket_registry,
+        seeds = [TREASURY_SEED, mint_account.key().as_ref()],
+        payer = signer,
+        bump
+    )]
+    pub platform_treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = sysvar::rent::ID)]
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateTreasuryVaultContext<'info> {
+    pub fn execute(&mut self, treasury_vault_bump: u8) -> Result<()> {
+        // record the treasury vault
+        self.pocket_registry.set_treasury_vault(
+            self.mint_account.key(),
+            self.platform_treasury_vault.key(),
+            treasury_vault_bump,
+        ).unwrap();
+
+        // emit event
+        pocket_emit!(
+            TreasuryVaultCreated {
+                actor: self.signer.key().clone(),
+                mint_account: self.mint_account.key().clone(),
+                associated_account: self.platform_treasury_vault.key().clone()
+            }
+        );
+
+        Ok(())
+    }
+}
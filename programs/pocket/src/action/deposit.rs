@@ -18,31 +18,46 @@ pub struct DepositContext<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    /// CHECK: skip check
+    /// CHECK: the token account is only loaded and verified when the base token is deposited
     #[account(mut)]
     pub signer_base_token_account: AccountInfo<'info>,
 
-    /// CHECK: skip check
+    /// CHECK: the token account is only loaded and verified when the quote token is deposited
     #[account(mut)]
     pub signer_quote_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner
+        constraint = pocket.has_permission(signer.key(), DelegatePermission::Deposit) @ PocketError::OnlyOwnerOrDelegate
     )]
     pub pocket: Account<'info, Pocket>,
 
-    /// CHECK: skip check
+    /// CHECK: the index of the owner, skipped when the owner never created one
     #[account(
-     mut,
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        mut,
+        address = Pocket::find_token_vault_address(pocket.key(), pocket.base_token_mint_address) @ PocketError::InvalidTokenVault
     )]
-    pub pocket_base_token_vault: AccountInfo<'info>,
+    pub pocket_base_token_vault: Account<'info, TokenAccount>,
 
-    /// CHECK: skip check
     #[account(
         mut,
+        address = Pocket::find_token_vault_address(pocket.key(), pocket.quote_token_mint_address) @ PocketError::InvalidTokenVault
     )]
-    pub pocket_quote_token_vault: AccountInfo<'info>,
+    pub pocket_quote_token_vault: Account<'info, TokenAccount>,
 
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
@@ -57,6 +72,19 @@ impl<'info> DepositContext<'info> {
 
         assert_eq!(pocket.is_able_to_deposit(), true, "NOT_ABLE_TO_DEPOSIT");
 
+        let (signer_token_account, pocket_token_vault) = match params.mode {
+            DepositedTokenType::Base => (&self.signer_base_token_account, &self.pocket_base_token_vault),
+            DepositedTokenType::Quote => (&self.signer_quote_token_account, &self.pocket_quote_token_vault),
+        };
+
+        // only the token account of the deposited mint is loaded, it must hold the mint of the vault
+        let signer_token_account: Account<TokenAccount> = Account::try_from(signer_token_account).unwrap();
+
+        if signer_token_account.mint != pocket_token_vault.mint {
+            msg!("ERROR::POCKET::INVALID_TOKEN_ACCOUNT");
+            return Err(PocketError::InvalidTokenAccount.into());
+        }
+
         match params.mode {
             DepositedTokenType::Base => {
                 // transfer the token
@@ -64,7 +92,7 @@ impl<'info> DepositContext<'info> {
                     CpiContext::new(
                         self.token_program.to_account_info(),
                         Transfer {
-                            from: self.signer_base_token_account.to_account_info(),
+                            from: signer_token_account.to_account_info(),
                             to: self.pocket_base_token_vault.to_account_info(),
                             authority: self.signer.to_account_info(),
                         },
@@ -83,7 +111,7 @@ impl<'info> DepositContext<'info> {
                     CpiContext::new(
                         self.token_program.to_account_info(),
                         Transfer {
-                            from: self.signer_quote_token_account.to_account_info(),
+                            from: signer_token_account.to_account_info(),
                             to: self.pocket_quote_token_vault.to_account_info(),
                             authority: self.signer.to_account_info(),
                         },
@@ -115,6 +143,27 @@ impl<'info> DepositContext<'info> {
             }
         );
 
+        // The pocket which ran out of balance runs again once the token to be swapped is topped up
+        let mint_address = match params.mode {
+            DepositedTokenType::Base => pocket.base_token_mint_address,
+            DepositedTokenType::Quote => pocket.quote_token_mint_address,
+        };
+
+        if pocket.is_able_to_restart_on_deposit() && pocket.is_swapped_token(mint_address) {
+            pocket.reopen(Clock::get().unwrap().unix_timestamp as u64);
+
+            pocket_emit!(
+                PocketUpdated {
+                    actor: self.signer.key(),
+                    pocket_address: pocket.key(),
+                    status: pocket.status,
+                    memo: String::from("RESTARTED_ON_DEPOSIT")
+                }
+            );
+
+            sync_indexed_pocket_status(&self.pocket_owner_index, self.pocket.key(), self.pocket.status).unwrap();
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,102 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from a multisig signer.
+#[derive(Accounts)]
+pub struct ExecuteMultisigActionContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_multisig_signer(signer.key()) @ PocketError::OnlyMultisigSigner
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig_proposal.id.to_le_bytes().as_ref()],
+        bump = multisig_proposal.bump,
+    )]
+    pub multisig_proposal: Account<'info, MultisigProposal>,
+
+    /// CHECK: the rent of the proposal is refunded to the proposer
+    #[account(mut, address = multisig_proposal.proposer)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> ExecuteMultisigActionContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        let pocket_registry = &mut self.pocket_registry;
+        let multisig_proposal = &self.multisig_proposal;
+
+        if !pocket_registry.is_multisig_approved(&multisig_proposal.approvals) {
+            return Err(PocketError::NotEnoughApprovals.into());
+        }
+
+        let action = multisig_proposal.action.clone();
+
+        // timelocked actions are queued, and can be executed once the timelock expired
+        if action.is_timelocked() && pocket_registry.is_timelock_enabled() {
+            let queued_change = pocket_registry.queue_change(
+                multisig_proposal.proposer,
+                action.clone(),
+                Clock::get().unwrap().unix_timestamp as u64,
+            ).unwrap();
+
+            pocket_emit!(
+                AdminChangeQueued {
+                    actor: self.signer.key(),
+                    change_id: queued_change.id,
+                    action: queued_change.action,
+                    eta: queued_change.eta
+                }
+            );
+        } else {
+            // the markets are read before the change, so that a removed market is still described by its event
+            let allowed_markets = pocket_registry.allowed_markets.clone();
+
+            pocket_registry.apply_admin_action(&action).unwrap();
+            emit_admin_action_applied(self.signer.key(), &action, &allowed_markets);
+        }
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        pocket_emit!(
+            MultisigActionExecuted {
+                actor: self.signer.key(),
+                proposal_id: multisig_proposal.id,
+                action
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn cancel(&mut self) -> Result<()> {
+        if self.multisig_proposal.proposer != self.signer.key() {
+            return Err(PocketError::OnlyProposer.into());
+        }
+
+        pocket_emit!(
+            MultisigActionCancelled {
+                actor: self.signer.key(),
+                proposal_id: self.multisig_proposal.id
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -28,6 +28,10 @@ pub fn handle_execute_swap<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteSwapCon
 fn swap<'info>(ctx: &Context<'_, '_, '_, 'info, ExecuteSwapContext<'info>>) -> Result<DidSwap> {
     let pocket = &ctx.accounts.pocket;
 
+    // The missed batches can be swapped at once depending on the catch-up policy
+    let (batch_amount, _) = pocket.plan_catch_up(Clock::get().unwrap().unix_timestamp as u64);
+    let batch_volume = pocket.batch_volume.saturating_mul(batch_amount);
+
     // Determine side
     let mut side = Side::Ask;
     if pocket.side == TradeSide::Buy {
@@ -36,16 +40,16 @@ fn swap<'info>(ctx: &Context<'_, '_, '_, 'info, ExecuteSwapContext<'info>>) -> R
 
     let amount_to_swap = match pocket.side {
         TradeSide::Buy => {
-            if pocket.batch_volume <= pocket.quote_token_balance {
-                pocket.batch_volume
+            if batch_volume <= pocket.quote_token_balance {
+                batch_volume
             } else {
                 pocket.quote_token_balance
             }
         }
 
         TradeSide::Sell => {
-            if pocket.batch_volume <= pocket.base_token_balance {
-                pocket.batch_volume
+            if batch_volume <= pocket.base_token_balance {
+                batch_volume
             } else {
                 pocket.base_token_balance
             }
@@ -107,6 +111,14 @@ pub struct ExecuteSwapContext<'info> {
     #[account(mut)]
     pub pocket: Account<'info, Pocket>,
 
+    /// CHECK: the index of the owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
     /// CHECK: skip verification
     #[account(
         mut,
@@ -117,6 +129,7 @@ pub struct ExecuteSwapContext<'info> {
     #[account(
         seeds = [PLATFORM_SEED],
         bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
     )]
     pub pocket_registry: Account<'info, PocketPlatformRegistry>,
 
@@ -126,6 +139,10 @@ pub struct ExecuteSwapContext<'info> {
     #[account(mut)]
     pub pocket_quote_token_vault: Account<'info, TokenAccount>,
 
+    /// CHECK: the treasury vault is only verified against the received mint when a platform fee is charged
+    #[account(mut)]
+    pub platform_treasury_vault: AccountInfo<'info>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -141,102 +158,160 @@ impl<'info> ExecuteSwapContext<'info> {
         // Validate if the swap matched price condition
         self.check_for_swap_possibility(did_swap).unwrap();
 
+        // Charge the platform fee on the received amount
+        let fee_amount = self.charge_platform_fee(did_swap).unwrap();
+
         // Update pocket balance
-        self.update_pocket_info(did_swap).unwrap();
+        self.update_pocket_info(did_swap, fee_amount).unwrap();
 
         // Update Pocket status if matches stop condition
         self.update_pocket_status().unwrap();
 
+        // Tip the operator for the executed batch
+        self.pay_keeper_tip().unwrap();
+
         Ok(())
     }
 
-    fn update_pocket_info(&mut self, swap_data: &DidSwap) -> Result<()> {
+    fn charge_platform_fee(&mut self, swap_data: &DidSwap) -> Result<u64> {
+        let did_swap = swap_data.clone();
+        let pocket = &self.pocket;
+
+        let fee_amount = self.pocket_registry.compute_platform_fee(did_swap.to_amount);
+
+        if fee_amount == 0 {
+            return Ok(0);
+        }
+
+        // The fee is taken in the received token
+        if self.platform_treasury_vault.key() != PocketPlatformRegistry::find_treasury_vault_address(did_swap.to_mint) {
+            msg!("ERROR::PLATFORM::INVALID_TREASURY_VAULT");
+            return Err(PocketError::InvalidTreasuryVault.into());
+        }
+
+        let pocket_token_vault = match pocket.side {
+            TradeSide::Buy => self.pocket_base_token_vault.to_account_info(),
+            TradeSide::Sell => self.pocket_quote_token_vault.to_account_info(),
+        };
+
+        // find the seeds to sign with the pda
+        pocket_signer!(pocket, signer);
+
+        // transfer the fee to the treasury
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: pocket_token_vault,
+                    to: self.platform_treasury_vault.to_account_info(),
+                    authority: pocket.to_account_info(),
+                },
+                signer,
+            ),
+            fee_amount,
+        ).unwrap();
+
+        // Emit event
+        pocket_emit!(PlatformFeeCharged {
+            pocket_address: pocket.key(),
+            mint_address: did_swap.to_mint,
+            amount: fee_amount
+        });
+
+        Ok(fee_amount)
+    }
+
+    fn update_pocket_info(&mut self, swap_data: &DidSwap, fee_amount: u64) -> Result<()> {
         let did_swap = swap_data.clone();
         let pocket = &mut self.pocket;
 
+        // The platform fee never reaches the pocket
+        let received_amount = did_swap.to_amount - fee_amount;
+
         // Update pocket balance
         match pocket.side {
             TradeSide::Buy => {
-                pocket.base_token_balance = pocket.base_token_balance + did_swap.to_amount;
+                pocket.base_token_balance = pocket.base_token_balance + received_amount;
                 pocket.quote_token_balance = pocket.quote_token_balance - did_swap.from_amount;
             }
 
             TradeSide::Sell => {
                 pocket.base_token_balance = pocket.base_token_balance - did_swap.from_amount;
-                pocket.quote_token_balance = pocket.quote_token_balance + did_swap.to_amount;
+                pocket.quote_token_balance = pocket.quote_token_balance + received_amount;
             }
         }
 
-        // Update pocket info
-        pocket.next_scheduled_execution_at = Clock::get().unwrap().unix_timestamp as u64 + pocket.frequency.hours.saturating_mul(3600);
-        pocket.executed_batch_amount = pocket.executed_batch_amount + 1;
+        // Update pocket info, the batches swapped at once are counted as executed
+        let now = Clock::get().unwrap().unix_timestamp as u64;
+        let (batch_amount, skipped_batch_amount) = pocket.plan_catch_up(now);
+
+        pocket.next_scheduled_execution_at = pocket.get_next_scheduled_execution_at(now);
+        pocket.executed_batch_amount = pocket.executed_batch_amount + batch_amount;
+
+        // Report the missed batches which were skipped
+        if skipped_batch_amount > 0 {
+            pocket.skipped_batch_amount = pocket.skipped_batch_amount + skipped_batch_amount;
+
+            pocket_emit!(PocketBatchesSkipped {
+                pocket_address: pocket.key(),
+                skipped_batch_amount,
+                total_skipped_batch_amount: pocket.skipped_batch_amount
+            });
+        }
 
         Ok(())
     }
 
-    fn update_pocket_status(&mut self) -> Result<()> {
-        let mut should_stop = false;
-        let pocket = &mut self.pocket;
+    fn pay_keeper_tip(&mut self) -> Result<()> {
+        let tip_amount = self.pocket.get_keeper_tip_amount(self.pocket_registry.keeper_tip_amount);
 
-        for condition in pocket.stop_conditions.clone() {
-            match condition {
-                StopCondition::EndTimeReach { value, .. } => {
-                    if value <= Clock::get().unwrap().unix_timestamp as u64 {
-                        should_stop = true;
-                        break;
-                    }
-                }
-
-                StopCondition::BaseTokenAmountReach { value,.. } => {
-                    if value <= pocket.base_token_balance {
-                        should_stop = true;
-                        break;
-                    }
-                }
-
-                StopCondition::QuoteTokenAmountReach { value, .. } => {
-                    if value <= pocket.quote_token_balance {
-                        should_stop = true;
-                        break;
-                    }
-                }
-
-                StopCondition::SpentBaseTokenAmountReach { value, .. } => {
-                    if value <= pocket.total_base_deposit_amount.saturating_sub(pocket.base_token_balance) {
-                        should_stop = true;
-                        break;
-                    }
-                }
-
-                StopCondition::SpentQuoteTokenAmountReach { value, .. } => {
-                    if value <= pocket.total_quote_deposit_amount.saturating_sub(pocket.quote_token_balance) {
-                        should_stop = true;
-                        break;
-                    }
-                }
-
-                StopCondition::BatchAmountReach { value, .. } => {
-                    if value <= pocket.executed_batch_amount {
-                        should_stop = true;
-                        break;
-                    }
-                }
-            }
+        if tip_amount == 0 {
+            return Ok(());
         }
 
+        // The reserved lamports are held by the pocket account itself
+        **self.pocket.to_account_info().try_borrow_mut_lamports()? -= tip_amount;
+        **self.signer.to_account_info().try_borrow_mut_lamports()? += tip_amount;
+
+        let pocket = &mut self.pocket;
+        pocket.keeper_tip_balance = pocket.keeper_tip_balance - tip_amount;
+
+        // Emit event
+        pocket_emit!(KeeperTipPaid {
+            operator: self.signer.key(),
+            pocket_address: pocket.key(),
+            amount: tip_amount
+        });
+
+        Ok(())
+    }
+
+    fn update_pocket_status(&mut self) -> Result<()> {
+        let pocket = &mut self.pocket;
+        let now = Clock::get().unwrap().unix_timestamp as u64;
+
         // Force close pocket
-        if should_stop {
-            pocket.status = PocketStatus::Closed;
-        }
+        let memo = if pocket.is_stop_condition_reached(now) {
+            pocket.mark_closed(PocketCloseReason::StopConditionReached);
+            "STOP_CONDITION_REACHED"
+        } else if pocket.is_balance_exhausted() && pocket.auto_restart_on_deposit {
+            // the pocket only closes when it runs out of balance if the owner opted in to restart it on deposit
+            pocket.mark_closed(PocketCloseReason::BalanceExhausted);
+            "BALANCE_EXHAUSTED"
+        } else {
+            "STOP_CONDITION_REACHED"
+        };
 
         // Emit event
         pocket_emit!(PocketUpdated {
             actor: self.signer.key(),
             pocket_address: pocket.key(),
             status: pocket.status,
-            memo: String::from("STOP_CONDITION_REACHED")
+            memo: String::from(memo)
         });
 
+        sync_indexed_pocket_status(&self.pocket_owner_index, self.pocket.key(), self.pocket.status).unwrap();
+
         Ok(())
     }
 
@@ -251,73 +326,16 @@ impl<'info> ExecuteSwapContext<'info> {
             "NOT_READY_TO_SWAP"
         );
 
-        // Check for buy condition
-        match pocket.buy_condition.clone() {
-            None => {}
+        // Check for buy condition, the batches swapped at once are compared by the amount received per batch
+        if pocket.buy_condition.is_some() {
+            let (batch_amount, _) = pocket.plan_catch_up(Clock::get().unwrap().unix_timestamp as u64);
+            let received_batch_amount = did_swap.to_amount / batch_amount.max(1);
 
-            Some(PriceCondition::Bw { from_value, to_value }) => {
-                assert_eq!(
-                    did_swap.to_amount <= to_value && did_swap.to_amount >= from_value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Nbw { from_value, to_value }) => {
-                assert_eq!(
-                    did_swap.to_amount <= to_value && did_swap.to_amount >= from_value,
-                    false,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Gt { value }) => {
-                assert_eq!(
-                    did_swap.to_amount > value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Gte { value }) => {
-                assert_eq!(
-                    did_swap.to_amount >= value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Lt { value }) => {
-                assert_eq!(
-                    did_swap.to_amount < value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Lte { value }) => {
-                assert_eq!(
-                    did_swap.to_amount <= value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Eq { value }) => {
-                assert_eq!(
-                    did_swap.to_amount == value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
-
-            Some(PriceCondition::Neq { value }) => {
-                assert_eq!(
-                    did_swap.to_amount != value,
-                    true,
-                    "BUY_CONDITION_NOT_FULFILLED"
-                );
-            }
+            assert_eq!(
+                PriceCondition::is_fulfilled(&pocket.buy_condition.unwrap(), received_batch_amount),
+                true,
+                "BUY_CONDITION_NOT_FULFILLED"
+            );
         }
 
         Ok(())
@@ -8,6 +8,7 @@ pub struct InitializePocketPlatformParams {
 
 // Define the context, passed in parameters when trigger from deployer.
 #[derive(Accounts)]
+#[instruction(params: InitializePocketPlatformParams)]
 pub struct InitializePocketPlatformContext<'info> {
     // We define the fee payer
     #[account(mut)]
@@ -17,7 +18,7 @@ pub struct InitializePocketPlatformContext<'info> {
         init,
         seeds = [PLATFORM_SEED],
         payer = owner,
-        space = 10240,
+        space = PocketPlatformRegistry::initial_space(params.operators.len()),
         bump
     )]
     pub pocket_registry: Account<'info, PocketPlatformRegistry>,
@@ -36,7 +37,10 @@ impl<'info> InitializePocketPlatformContext<'info> {
         let pocket_registry = &mut self.pocket_registry;
         pocket_registry.bump = bump;
         pocket_registry.owner = *self.owner.key;
-        pocket_registry.operators = params.operators;
+        pocket_registry.apply_admin_action(&AdminAction::UpdateOperators {
+            operators: params.operators
+        }).unwrap();
+        pocket_registry.version = REGISTRY_VERSION;
 
         Ok(())
     }
@@ -0,0 +1,58 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from anyone, the signer pays for the extra rent.
+#[derive(Accounts)]
+pub struct MigratePocketContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: the legacy layout is deserialized after the account is reallocated
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub pocket: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> MigratePocketContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        // the fixed part of the layout is an upper bound of the appended fields
+        let mut pocket: Pocket = load_legacy_account_state(
+            &self.pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+            Pocket::space(0, 0, 0, 0),
+        ).unwrap();
+        let previous_version = pocket.version;
+
+        // make sure the account is the pocket PDA
+        assert_eq!(pocket.derive_address(), self.pocket.key(), "POCKET_ADDRESS_IS_NOT_VALID");
+
+        pocket.migrate().unwrap();
+
+        // the keeper tip is kept aside, the payer also covers the rent of the padding added above
+        realloc_account(
+            &self.pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+            pocket.current_space(),
+            pocket.keeper_tip_balance,
+        ).unwrap();
+        save_account_state(&self.pocket, &pocket).unwrap();
+
+        pocket_emit!(
+            AccountMigrated {
+                actor: self.signer.key(),
+                account: self.pocket.key(),
+                previous_version,
+                version: pocket.version
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from the registry owner.
+#[derive(Accounts)]
+pub struct MigrateRegistryContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: the legacy layout is deserialized after the account is reallocated
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump,
+        owner = crate::ID
+    )]
+    pub pocket_registry: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> MigrateRegistryContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        // the initial layout is an upper bound of the appended fields
+        let mut pocket_registry: PocketPlatformRegistry = load_legacy_account_state(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+            PocketPlatformRegistry::initial_space(0),
+        ).unwrap();
+        let previous_version = pocket_registry.version;
+
+        if pocket_registry.owner != self.signer.key() {
+            return Err(PocketError::OnlyOwner.into());
+        }
+
+        pocket_registry.migrate().unwrap();
+
+        realloc_account(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + pocket_registry.try_to_vec().unwrap().len(),
+            0,
+        ).unwrap();
+        save_account_state(&self.pocket_registry, &pocket_registry).unwrap();
+
+        pocket_emit!(
+            AccountMigrated {
+                actor: self.signer.key(),
+                account: self.pocket_registry.key(),
+                previous_version,
+                version: pocket_registry.version
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -2,20 +2,68 @@
 
 pub mod initialize_pocket_program;
 pub mod create_pocket;
+pub mod open_pocket;
+pub mod create_nonce_pocket;
 pub mod deposit;
 pub mod withdraw;
 pub mod update_pocket;
+pub mod transfer_pocket_ownership;
+pub mod accept_pocket_ownership;
+pub mod update_pocket_delegate;
 pub mod execute_swap;
+pub mod check_start_condition;
 pub mod create_token_vault;
 pub mod update_pocket_registry;
+pub mod propose_registry_owner;
+pub mod accept_registry_owner;
+pub mod update_platform_role;
+pub mod update_platform_status;
+pub mod update_admin_change;
+pub mod propose_multisig_action;
+pub mod approve_multisig_action;
+pub mod execute_multisig_action;
 pub mod close_pocket_accounts;
+pub mod teardown_pocket;
+pub mod sync_pocket_owner_index;
+pub mod update_mint_whitelist;
+pub mod update_market_whitelist;
+pub mod update_platform_fee;
+pub mod create_treasury_vault;
+pub mod claim_platform_fee;
+pub mod top_up_keeper_tip;
+pub mod migrate_pocket;
+pub mod migrate_registry;
 
 pub use initialize_pocket_program::*;
 pub use create_pocket::*;
+pub use open_pocket::*;
+pub use create_nonce_pocket::*;
 pub use deposit::*;
 pub use withdraw::*;
 pub use update_pocket::*;
+pub use transfer_pocket_ownership::*;
+pub use accept_pocket_ownership::*;
+pub use update_pocket_delegate::*;
 pub use execute_swap::*;
+pub use check_start_condition::*;
 pub use create_token_vault::*;
 pub use update_pocket_registry::*;
+pub use propose_registry_owner::*;
+pub use accept_registry_owner::*;
+pub use update_platform_role::*;
+pub use update_platform_status::*;
+pub use update_admin_change::*;
+pub use propose_multisig_action::*;
+pub use approve_multisig_action::*;
+pub use execute_multisig_action::*;
 pub use close_pocket_accounts::*;
+pub use teardown_pocket::*;
+pub use sync_pocket_owner_index::*;
+pub use update_mint_whitelist::*;
+pub use update_market_whitelist::*;
+pub use update_platform_fee::*;
+pub use create_treasury_vault::*;
+pub use claim_platform_fee::*;
+pub use top_up_keeper_tip::*;
+pub use migrate_pocket::*;
+pub use migrate_registry::*;
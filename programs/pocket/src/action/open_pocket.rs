@@ -0,0 +1,260 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct OpenPocketParams {
+    // Define the pocket to be created
+    pub pocket: CreatePocketParams,
+
+    // Define the first deposit of the base token, zero to skip
+    pub base_deposit_amount: u64,
+
+    // Define the first deposit of the quote token, zero to skip
+    pub quote_deposit_amount: u64,
+}
+
+// Create the pocket, its vaults and open orders account and make the first deposit at once,
+// so that a failed setup doesn't leave orphaned accounts behind.
+#[derive(Accounts)]
+#[instruction(params: OpenPocketParams)]
+pub struct OpenPocketContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [POCKET_SEED, signer.key().as_ref(), params.pocket.id.as_bytes().as_ref()],
+        payer = signer,
+        space = Pocket::space(params.pocket.id.len(), params.pocket.name.len(), params.pocket.stop_conditions.len(), 0),
+        bump
+    )]
+    pub pocket: Box<Account<'info, Pocket>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [POCKET_OWNER_INDEX_SEED, signer.key().as_ref()],
+        payer = signer,
+        space = PocketOwnerIndex::space(0),
+        bump
+    )]
+    pub pocket_owner_index: Box<Account<'info, PocketOwnerIndex>>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Box<Account<'info, PocketPlatformRegistry>>,
+
+    #[account(address = params.pocket.base_token_address)]
+    pub base_mint_account: Box<Account<'info, Mint>>,
+
+    #[account(address = params.pocket.quote_token_address)]
+    pub quote_mint_account: Box<Account<'info, Mint>>,
+
+    #[account(init,
+        token::mint = base_mint_account,
+        token::authority = pocket,
+        seeds = [TOKEN_ACCOUNT_SEED, pocket.key().as_ref(), base_mint_account.key().as_ref()],
+        payer = signer,
+        bump
+    )]
+    pub pocket_base_token_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(init,
+        token::mint = quote_mint_account,
+        token::authority = pocket,
+        seeds = [TOKEN_ACCOUNT_SEED, pocket.key().as_ref(), quote_mint_account.key().as_ref()],
+        payer = signer,
+        bump
+    )]
+    pub pocket_quote_token_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the token account is only loaded and verified when the base token is deposited
+    #[account(mut)]
+    pub signer_base_token_account: AccountInfo<'info>,
+
+    /// CHECK: the token account is only loaded and verified when the quote token is deposited
+    #[account(mut)]
+    pub signer_quote_token_account: AccountInfo<'info>,
+
+    /// CHECK: the open orders account is created by the instruction and initialized by the dex
+    #[account(
+        mut,
+        seeds = [OPEN_ORDERS_SEED, pocket.key().as_ref(), market_key.key().as_ref()],
+        bump
+    )]
+    pub open_orders: AccountInfo<'info>,
+
+    /// CHECK: the market state is verified against the whitelisted market
+    #[account(address = params.pocket.market_key)]
+    pub market_key: AccountInfo<'info>,
+
+    /// CHECK: the dex program is verified against the whitelisted market
+    #[account(executable)]
+    pub dex_program: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = sysvar::rent::ID)]
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> OpenPocketContext<'info> {
+    pub fn execute(&mut self, params: OpenPocketParams, pocket_bump: u8, index_bump: u8, open_orders_bump: u8) -> Result<()> {
+        // Only whitelisted mint accounts and market of the token pair can be traded
+        let market_info = validate_pocket_market(&self.pocket_registry, &self.market_key, &params.pocket).unwrap();
+
+        if self.dex_program.key() != market_info.dex_program {
+            return Err(PocketError::MarketNotAllowed.into());
+        }
+
+        // Reserve lamports for operator tips
+        reserve_keeper_tip(
+            &self.system_program.to_account_info(),
+            &self.signer.to_account_info(),
+            &self.pocket.to_account_info(),
+            params.pocket.keeper_tip_deposit,
+        ).unwrap();
+
+        // Update pocket state
+        initialize_pocket(&mut self.pocket, self.signer.key(), params.pocket, PocketAddressSeed::OwnerId { owner: self.signer.key() }, pocket_bump).unwrap();
+
+        // List the pocket in the owner index
+        index_pocket(
+            &mut self.pocket_owner_index,
+            self.signer.key(),
+            index_bump,
+            &self.pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        // emit vault events
+        for (pocket_token_vault, mint_account) in [
+            (self.pocket_base_token_vault.key(), self.base_mint_account.key()),
+            (self.pocket_quote_token_vault.key(), self.quote_mint_account.key()),
+        ] {
+            pocket_emit!(
+                VaultCreated {
+                    actor: self.signer.key(),
+                    authority: self.pocket.key(),
+                    associated_account: pocket_token_vault,
+                    mint_account,
+                    pocket_address: self.pocket.key()
+                }
+            );
+        }
+
+        // Create the open orders account used for swapping
+        self.init_open_orders(open_orders_bump).unwrap();
+
+        // Make the first deposit
+        if params.base_deposit_amount > 0 {
+            self.deposit(DepositedTokenType::Base, params.base_deposit_amount).unwrap();
+        }
+
+        if params.quote_deposit_amount > 0 {
+            self.deposit(DepositedTokenType::Quote, params.quote_deposit_amount).unwrap();
+        }
+
+        Ok(())
+    }
+
+    fn init_open_orders(&self, open_orders_bump: u8) -> Result<()> {
+        let pocket_key = self.pocket.key();
+        let market_key = self.market_key.key();
+
+        // find the bump to sign with the pda
+        let bump = &[open_orders_bump][..];
+        let signer = &[&[OPEN_ORDERS_SEED, pocket_key.as_ref(), market_key.as_ref(), bump][..]];
+
+        // the open orders account must be owned by the dex before it is initialized
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: self.signer.to_account_info(),
+                    to: self.open_orders.to_account_info(),
+                },
+                signer,
+            ),
+            self.rent.minimum_balance(OPEN_ORDERS_ACCOUNT_SIZE),
+            OPEN_ORDERS_ACCOUNT_SIZE as u64,
+            &self.dex_program.key(),
+        ).unwrap();
+
+        init_account(
+            &InitAccount {
+                open_orders: self.open_orders.to_account_info(),
+                dex_program: self.dex_program.to_account_info(),
+                authority: self.pocket.to_account_info(),
+                market_key: self.market_key.to_account_info(),
+                rent: self.rent.to_account_info(),
+                pocket: (*self.pocket).clone(),
+            }
+        ).unwrap();
+
+        Ok(())
+    }
+
+    fn deposit(&mut self, mode: DepositedTokenType, amount: u64) -> Result<()> {
+        let (signer_token_account, pocket_token_vault) = match mode {
+            DepositedTokenType::Base => (&self.signer_base_token_account, &self.pocket_base_token_vault),
+            DepositedTokenType::Quote => (&self.signer_quote_token_account, &self.pocket_quote_token_vault),
+        };
+
+        // the token account must hold the mint of the vault
+        let signer_token_account: Account<TokenAccount> = Account::try_from(signer_token_account).unwrap();
+
+        if signer_token_account.mint != pocket_token_vault.mint {
+            msg!("ERROR::POCKET::INVALID_TOKEN_ACCOUNT");
+            return Err(PocketError::InvalidTokenAccount.into());
+        }
+
+        // transfer the token
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: signer_token_account.to_account_info(),
+                    to: pocket_token_vault.to_account_info(),
+                    authority: self.signer.to_account_info(),
+                },
+            ),
+            amount,
+        ).unwrap();
+
+        // update credited balance
+        let pocket = &mut self.pocket;
+
+        let mint_address = match mode {
+            DepositedTokenType::Base => {
+                pocket.base_token_balance += amount;
+                pocket.total_base_deposit_amount += amount;
+                pocket.base_token_mint_address
+            }
+
+            DepositedTokenType::Quote => {
+                pocket.quote_token_balance += amount;
+                pocket.total_quote_deposit_amount += amount;
+                pocket.quote_token_mint_address
+            }
+        };
+
+        // emit event
+        pocket_emit!(
+            PocketDeposited {
+               owner: self.signer.key(),
+               pocket_address: pocket.key(),
+               mint_address,
+               amount,
+            }
+        );
+
+        Ok(())
+    }
+}
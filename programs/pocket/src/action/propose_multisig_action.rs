@@ -0,0 +1,67 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ProposeMultisigActionParams {
+    pub action: AdminAction,
+}
+
+// Define the context, passed in parameters when trigger from a multisig signer.
+#[derive(Accounts)]
+#[instruction(params: ProposeMultisigActionParams)]
+pub struct ProposeMultisigActionContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_multisig_signer(signer.key()) @ PocketError::OnlyMultisigSigner
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = pocket_registry.is_multisig_enabled() @ PocketError::MultisigNotEnabled
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(
+        init,
+        seeds = [MULTISIG_PROPOSAL_SEED, pocket_registry.next_proposal_id.to_le_bytes().as_ref()],
+        payer = signer,
+        space = MultisigProposal::space(&params.action),
+        bump
+    )]
+    pub multisig_proposal: Account<'info, MultisigProposal>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> ProposeMultisigActionContext<'info> {
+    pub fn execute(&mut self, params: ProposeMultisigActionParams, bump: u8) -> Result<()> {
+        let pocket_registry = &mut self.pocket_registry;
+        let multisig_proposal = &mut self.multisig_proposal;
+
+        multisig_proposal.id = pocket_registry.next_proposal_id;
+        multisig_proposal.bump = bump;
+        multisig_proposal.proposer = self.signer.key();
+        multisig_proposal.action = params.action;
+        multisig_proposal.created_at = Clock::get().unwrap().unix_timestamp as u64;
+
+        // the proposer approves the action by default
+        multisig_proposal.approve(self.signer.key()).unwrap();
+
+        pocket_registry.next_proposal_id = pocket_registry.next_proposal_id.checked_add(1).unwrap();
+
+        pocket_emit!(
+            MultisigActionProposed {
+                actor: self.signer.key(),
+                proposal_id: multisig_proposal.id,
+                action: multisig_proposal.action.clone()
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ProposeRegistryOwnerParams {
+    pub new_owner: Pubkey,
+}
+
+// Define the context, passed in parameters when trigger from the current owner.
+// The new owner is proposed through the multisig once it is enabled.
+#[derive(Accounts)]
+pub struct ProposeRegistryOwnerContext<'info> {
+    #[account(
+        mut,
+        address = pocket_registry.owner @ PocketError::OnlyAdministrator
+    )]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        has_one = owner,
+        constraint = !pocket_registry.is_multisig_enabled() @ PocketError::MultisigRequired
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> ProposeRegistryOwnerContext<'info> {
+    pub fn execute(&mut self, params: ProposeRegistryOwnerParams) -> Result<()> {
+        assert_ne!(params.new_owner, Pubkey::default(), "OWNER_IS_NOT_VALID");
+
+        let pocket_registry = &mut self.pocket_registry;
+        pocket_registry.pending_owner = Some(params.new_owner);
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.owner.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        pocket_emit!(
+            RegistryOwnerProposed {
+                actor: self.owner.key(),
+                pending_owner: params.new_owner
+            }
+        );
+
+        Ok(())
+    }
+}
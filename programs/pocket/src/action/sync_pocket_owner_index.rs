@@ -0,0 +1,98 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from anyone, the signer pays for the index rent.
+// The pocket is listed in the index of its owner, and removed from the index of a previous owner.
+#[derive(Accounts)]
+pub struct SyncPocketOwnerIndexContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    /// CHECK: the owner of the synced index
+    pub owner: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [POCKET_OWNER_INDEX_SEED, owner.key().as_ref()],
+        payer = signer,
+        space = PocketOwnerIndex::space(0),
+        bump
+    )]
+    pub pocket_owner_index: Account<'info, PocketOwnerIndex>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SyncPocketOwnerIndexContext<'info> {
+    pub fn execute(&mut self, index_bump: u8) -> Result<()> {
+        if self.pocket.owner != self.owner.key() {
+            self.pocket_owner_index.initialize(self.owner.key(), index_bump);
+            self.pocket_owner_index.remove_pocket(self.pocket.key());
+
+            return Ok(());
+        }
+
+        index_pocket(
+            &mut self.pocket_owner_index,
+            self.owner.key(),
+            index_bump,
+            &self.pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        )
+    }
+}
+
+// List the pocket in the owner index, or refresh its status. A full index never blocks the pocket creation.
+pub fn index_pocket<'info>(
+    pocket_owner_index: &mut Account<'info, PocketOwnerIndex>,
+    owner: Pubkey,
+    index_bump: u8,
+    pocket: &Account<'info, Pocket>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    pocket_owner_index.initialize(owner, index_bump);
+
+    if !pocket_owner_index.upsert_pocket(pocket.key(), pocket.status) {
+        pocket_emit!(
+            PocketIndexSkipped {
+                owner,
+                pocket_address: pocket.key()
+            }
+        );
+
+        return Ok(());
+    }
+
+    realloc_account_to_fit(pocket_owner_index, payer, system_program)
+}
+
+// Remove the closed pocket from the owner index, the owners who never created an index are skipped
+pub fn unindex_pocket(pocket_owner_index: &AccountInfo, pocket_address: Pubkey) -> Result<()> {
+    if pocket_owner_index.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut state: PocketOwnerIndex = load_account_state(pocket_owner_index).unwrap();
+    state.remove_pocket(pocket_address);
+
+    save_account_state(pocket_owner_index, &state)
+}
+
+// Refresh the pocket status in the owner index, the owners who never created an index are skipped
+pub fn sync_indexed_pocket_status(pocket_owner_index: &AccountInfo, pocket_address: Pubkey, status: PocketStatus) -> Result<()> {
+    if pocket_owner_index.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut state: PocketOwnerIndex = load_account_state(pocket_owner_index).unwrap();
+    state.set_pocket_status(pocket_address, status);
+
+    save_account_state(pocket_owner_index, &state)
+}
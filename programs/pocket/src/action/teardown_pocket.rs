@@ -0,0 +1,193 @@
+use crate::*;
+
+// Withdraw the remaining assets, close the vaults, the open orders account and the pocket at once,
+// the rent is refunded to the owner.
+#[derive(Accounts)]
+pub struct TeardownPocketContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner,
+        close = signer
+    )]
+    pub pocket: Box<Account<'info, Pocket>>,
+
+    /// CHECK: the index of the owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = signer_base_token_account.mint == pocket.base_token_mint_address @ PocketError::InvalidTokenAccount
+    )]
+    pub signer_base_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = signer_quote_token_account.mint == pocket.quote_token_mint_address @ PocketError::InvalidTokenAccount
+    )]
+    pub signer_quote_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = Pocket::find_token_vault_address(pocket.key(), pocket.base_token_mint_address) @ PocketError::InvalidTokenVault
+    )]
+    pub pocket_base_token_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = Pocket::find_token_vault_address(pocket.key(), pocket.quote_token_mint_address) @ PocketError::InvalidTokenVault
+    )]
+    pub pocket_quote_token_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the open orders PDA of the pocket, an empty account is skipped for the pockets which never created one
+    #[account(
+        mut,
+        seeds = [OPEN_ORDERS_SEED, pocket.key().as_ref(), market_key.key().as_ref()],
+        bump,
+        constraint = open_orders.data_is_empty() || *open_orders.owner == dex_program.key() @ PocketError::InvalidOpenOrders
+    )]
+    pub open_orders: AccountInfo<'info>,
+
+    /// CHECK: the market of the pocket
+    #[account(
+        address = pocket.market_key,
+        owner = dex_program.key() @ PocketError::MarketNotAllowed
+    )]
+    pub market_key: AccountInfo<'info>,
+
+    /// CHECK: the dex program which owns the open orders account
+    #[account(executable)]
+    pub dex_program: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> TeardownPocketContext<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        // A running pocket is closed first
+        if self.pocket.is_able_to_close() {
+            self.pocket.mark_closed(PocketCloseReason::User);
+
+            pocket_emit!(
+                PocketUpdated {
+                    actor: self.signer.key(),
+                    pocket_address: self.pocket.key(),
+                    status: self.pocket.status,
+                    memo: String::from("USER_TORE_DOWN_POCKET")
+                }
+            );
+        }
+
+        if self.pocket.is_able_to_withdraw() {
+            self.withdraw().unwrap();
+        }
+
+        self.close_token_vaults().unwrap();
+        self.close_open_orders().unwrap();
+        unindex_pocket(&self.pocket_owner_index, self.pocket.key()).unwrap();
+
+        // The pocket account is closed on exit
+        Ok(())
+    }
+
+    fn withdraw(&mut self) -> Result<()> {
+        let pocket = &mut self.pocket;
+
+        let base_token_amount = self.pocket_base_token_vault.amount;
+        let quote_token_amount = self.pocket_quote_token_vault.amount;
+
+        // update credited balance & status
+        pocket.base_token_balance = 0;
+        pocket.quote_token_balance = 0;
+        pocket.status = PocketStatus::Withdrawn;
+
+        // find the seeds to sign with the pda
+        pocket_signer!(pocket, signer);
+
+        for (pocket_token_vault, signer_token_account, amount) in [
+            (&self.pocket_base_token_vault, &self.signer_base_token_account, base_token_amount),
+            (&self.pocket_quote_token_vault, &self.signer_quote_token_account, quote_token_amount),
+        ] {
+            // transfer the token
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: pocket_token_vault.to_account_info(),
+                        to: signer_token_account.to_account_info(),
+                        authority: pocket.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+            ).unwrap();
+        }
+
+        // emit event
+        pocket_emit!(
+            PocketWithdrawn {
+               owner: self.signer.key(),
+               pocket_address: pocket.key(),
+               base_token_mint_address: pocket.base_token_mint_address,
+               base_token_amount,
+               quote_token_mint_address: pocket.quote_token_mint_address,
+               quote_token_amount
+            }
+        );
+
+        Ok(())
+    }
+
+    fn close_token_vaults(&self) -> Result<()> {
+        let pocket = &self.pocket;
+
+        // find the seeds to sign with the pda
+        pocket_signer!(pocket, signer);
+
+        for pocket_token_vault in [&self.pocket_base_token_vault, &self.pocket_quote_token_vault] {
+            token::close_account(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::CloseAccount {
+                        account: pocket_token_vault.to_account_info(),
+                        destination: self.signer.to_account_info(),
+                        authority: pocket.to_account_info(),
+                    },
+                    signer,
+                )
+            ).unwrap();
+        }
+
+        Ok(())
+    }
+
+    fn close_open_orders(&self) -> Result<()> {
+        if self.open_orders.data_is_empty() {
+            return Ok(());
+        }
+
+        close_account(
+            &CloseAccount {
+                open_orders: self.open_orders.to_account_info(),
+                dex_program: self.dex_program.to_account_info(),
+                authority: self.pocket.to_account_info(),
+                destination: self.signer.to_account_info(),
+                market_key: self.market_key.to_account_info(),
+                pocket: (*self.pocket).clone(),
+            }
+        ).unwrap();
+
+        Ok(())
+    }
+}
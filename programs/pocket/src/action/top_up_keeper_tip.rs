@@ -0,0 +1,61 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct TopUpKeeperTipParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct TopUpKeeperTipContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_paused @ PocketError::PlatformPaused
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TopUpKeeperTipContext<'info> {
+    pub fn execute(&mut self, params: TopUpKeeperTipParams) -> Result<()> {
+        assert_eq!(self.pocket.status != PocketStatus::Withdrawn, true, "NOT_ABLE_TO_TOP_UP");
+
+        // transfer the lamports
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: self.signer.to_account_info(),
+                    to: self.pocket.to_account_info(),
+                },
+            ),
+            params.amount,
+        ).unwrap();
+
+        // update reserved balance
+        let pocket = &mut self.pocket;
+        pocket.keeper_tip_balance += params.amount;
+
+        // emit event
+        pocket_emit!(
+            KeeperTipToppedUp {
+                actor: self.signer.key(),
+                pocket_address: pocket.key(),
+                amount: params.amount
+            }
+        );
+
+        Ok(())
+    }
+}
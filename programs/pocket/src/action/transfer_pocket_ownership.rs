@@ -0,0 +1,108 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct TransferPocketOwnershipParams {
+    pub new_owner: Pubkey,
+
+    // Whether the new owner must accept the ownership before it is transferred,
+    // the current owner as the new owner cancels the pending transfer
+    pub require_acceptance: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(params: TransferPocketOwnershipParams)]
+pub struct TransferPocketOwnershipContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress,
+        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner,
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    /// CHECK: the index of the current owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    // The pocket is moved into the index of the new owner once the ownership is transferred
+    #[account(
+        init_if_needed,
+        seeds = [POCKET_OWNER_INDEX_SEED, params.new_owner.as_ref()],
+        payer = signer,
+        space = PocketOwnerIndex::space(0),
+        bump
+    )]
+    pub new_pocket_owner_index: Account<'info, PocketOwnerIndex>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransferPocketOwnershipContext<'info> {
+    pub fn execute(&mut self, params: TransferPocketOwnershipParams, new_index_bump: u8) -> Result<()> {
+        assert_ne!(params.new_owner, Pubkey::default(), "OWNER_IS_NOT_VALID");
+
+        let pocket = &mut self.pocket;
+
+        // Proposing the current owner cancels the pending transfer
+        if params.new_owner == pocket.owner {
+            assert_eq!(pocket.pending_owner.is_some(), true, "NO_PENDING_OWNER");
+
+            let pending_owner = pocket.pending_owner.unwrap();
+            pocket.pending_owner = None;
+
+            pocket_emit!(
+                PocketOwnerProposalCancelled {
+                    actor: self.signer.key(),
+                    pocket_address: pocket.key(),
+                    pending_owner
+                }
+            );
+
+            return Ok(());
+        }
+
+        if params.require_acceptance {
+            pocket.pending_owner = Some(params.new_owner);
+
+            pocket_emit!(
+                PocketOwnerProposed {
+                    actor: self.signer.key(),
+                    pocket_address: pocket.key(),
+                    pending_owner: params.new_owner
+                }
+            );
+
+            return Ok(());
+        }
+
+        let previous_owner = pocket.owner;
+        pocket.transfer_ownership(params.new_owner);
+
+        unindex_pocket(&self.pocket_owner_index, pocket.key()).unwrap();
+        index_pocket(
+            &mut self.new_pocket_owner_index,
+            params.new_owner,
+            new_index_bump,
+            pocket,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        pocket_emit!(
+            PocketOwnerTransferred {
+                pocket_address: pocket.key(),
+                previous_owner,
+                owner: pocket.owner
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,290 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct QueueAdminChangeParams {
+    pub action: AdminAction,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct AdminChangeParams {
+    pub change_id: u64,
+}
+
+// Define the context, passed in parameters when trigger from an admin or a fee manager.
+#[derive(Accounts)]
+pub struct AdminChangeContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> AdminChangeContext<'info> {
+    pub fn queue(&mut self, params: QueueAdminChangeParams) -> Result<()> {
+        self.ensure_role(params.action.required_role()).unwrap();
+
+        // Only the owner can manage the admins and the ownership
+        if params.action.is_owner_only() && self.pocket_registry.owner != self.signer.key() {
+            return Err(PocketError::OnlyOwner.into());
+        }
+
+        if self.pocket_registry.is_multisig_enabled() && params.action.is_multisig_required() {
+            return Err(PocketError::MultisigRequired.into());
+        }
+
+        let queued_change = self.pocket_registry.queue_change(
+            self.signer.key(),
+            params.action,
+            Clock::get().unwrap().unix_timestamp as u64,
+        ).unwrap();
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        pocket_emit!(
+            AdminChangeQueued {
+                actor: self.signer.key(),
+                change_id: queued_change.id,
+                action: queued_change.action,
+                eta: queued_change.eta
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn execute(&mut self, params: AdminChangeParams) -> Result<()> {
+        self.ensure_role_for_change(params.change_id).unwrap();
+
+        // the markets are read before the change, so that a removed market is still described by its event
+        let allowed_markets = self.pocket_registry.allowed_markets.clone();
+
+        let queued_change = self.pocket_registry.execute_change(
+            params.change_id,
+            Clock::get().unwrap().unix_timestamp as u64,
+        ).unwrap();
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        emit_admin_action_applied(self.signer.key(), &queued_change.action, &allowed_markets);
+
+        pocket_emit!(
+            AdminChangeExecuted {
+                actor: self.signer.key(),
+                change_id: queued_change.id,
+                action: queued_change.action
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, params: AdminChangeParams) -> Result<()> {
+        self.ensure_role_for_change(params.change_id).unwrap();
+
+        // A change approved by the multisig can't be dropped by a single role holder
+        if self.pocket_registry.is_multisig_enabled() && self.pocket_registry.owner != self.signer.key() {
+            return Err(PocketError::OnlyOwner.into());
+        }
+
+        let queued_change = self.pocket_registry.cancel_change(params.change_id).unwrap();
+
+        pocket_emit!(
+            AdminChangeCancelled {
+                actor: self.signer.key(),
+                change_id: queued_change.id,
+                action: queued_change.action
+            }
+        );
+
+        Ok(())
+    }
+
+    fn ensure_role_for_change(&self, change_id: u64) -> Result<()> {
+        return match self.pocket_registry.get_queued_change(change_id) {
+            Some(queued_change) => self.ensure_role(queued_change.action.required_role()),
+            None => Err(PocketError::QueuedChangeNotExisted.into()),
+        };
+    }
+
+    fn ensure_role(&self, role: PlatformRole) -> Result<()> {
+        if self.pocket_registry.has_role(role, self.signer.key()) {
+            return Ok(());
+        }
+
+        return match role {
+            PlatformRole::FeeManager => Err(PocketError::OnlyFeeManager.into()),
+            PlatformRole::MintCurator => Err(PocketError::OnlyMintCurator.into()),
+            PlatformRole::Pauser => Err(PocketError::OnlyPauser.into()),
+            _ => Err(PocketError::OnlyAdministrator.into()),
+        };
+    }
+}
+
+// Emit the same events as the direct updates, so that indexers keep working.
+// The markets are the whitelist before the action was applied.
+pub fn emit_admin_action_applied(actor: Pubkey, action: &AdminAction, allowed_markets: &Vec<MarketInfo>) {
+    match action.clone() {
+        AdminAction::UpdateOperators { operators } => {
+            pocket_emit!(
+                PocketConfigUpdated {
+                    actor,
+                    operators
+                }
+            );
+        }
+
+        AdminAction::UpdatePlatformFee { platform_fee_bps, keeper_tip_amount } => {
+            pocket_emit!(
+                PlatformFeeUpdated {
+                    actor,
+                    platform_fee_bps,
+                    keeper_tip_amount
+                }
+            );
+        }
+
+        AdminAction::AddMintAccount { mint_account } => {
+            pocket_emit!(
+                MintWhitelistUpdated {
+                    actor,
+                    mint_account,
+                    is_enabled: true,
+                    memo: String::from("MINT_ACCOUNT_ADDED")
+                }
+            );
+        }
+
+        AdminAction::SetMintAccountEnabled { mint_account, is_enabled } => {
+            pocket_emit!(
+                MintWhitelistUpdated {
+                    actor,
+                    mint_account,
+                    is_enabled,
+                    memo: String::from(if is_enabled { "MINT_ACCOUNT_ENABLED" } else { "MINT_ACCOUNT_DISABLED" })
+                }
+            );
+        }
+
+        AdminAction::RemoveMintAccount { mint_account } => {
+            pocket_emit!(
+                MintWhitelistUpdated {
+                    actor,
+                    mint_account,
+                    is_enabled: false,
+                    memo: String::from("MINT_ACCOUNT_REMOVED")
+                }
+            );
+        }
+
+        AdminAction::SetPaused { is_paused } => {
+            pocket_emit!(
+                PlatformStatusUpdated {
+                    actor,
+                    is_paused
+                }
+            );
+        }
+
+        AdminAction::GrantRole { role, account } => {
+            pocket_emit!(
+                RoleGranted {
+                    actor,
+                    role,
+                    account
+                }
+            );
+        }
+
+        AdminAction::RevokeRole { role, account } => {
+            pocket_emit!(
+                RoleRevoked {
+                    actor,
+                    role,
+                    account
+                }
+            );
+        }
+
+        AdminAction::ProposeRegistryOwner { new_owner } => {
+            pocket_emit!(
+                RegistryOwnerProposed {
+                    actor,
+                    pending_owner: new_owner
+                }
+            );
+        }
+
+        AdminAction::AddMarket { market_key, base_mint_address, quote_mint_address, dex_program } => {
+            pocket_emit!(
+                MarketWhitelistUpdated {
+                    actor,
+                    market_key,
+                    base_mint_address,
+                    quote_mint_address,
+                    dex_program,
+                    is_enabled: true,
+                    memo: String::from("MARKET_ADDED")
+                }
+            );
+        }
+
+        AdminAction::SetMarketEnabled { market_key, is_enabled } => {
+            emit_market_whitelist_updated(
+                actor,
+                market_key,
+                allowed_markets,
+                is_enabled,
+                if is_enabled { "MARKET_ENABLED" } else { "MARKET_DISABLED" },
+            );
+        }
+
+        AdminAction::RemoveMarket { market_key } => {
+            emit_market_whitelist_updated(actor, market_key, allowed_markets, false, "MARKET_REMOVED");
+        }
+
+        AdminAction::UpdateTimelockDelay { .. } | AdminAction::UpdateMultisig { .. } => {}
+    }
+}
+
+fn emit_market_whitelist_updated(
+    actor: Pubkey,
+    market_key: Pubkey,
+    allowed_markets: &Vec<MarketInfo>,
+    is_enabled: bool,
+    memo: &str,
+) {
+    let market_info = allowed_markets.iter()
+        .find(|market_info| market_info.market_key == market_key)
+        .cloned()
+        .unwrap_or_default();
+
+    pocket_emit!(
+        MarketWhitelistUpdated {
+            actor,
+            market_key,
+            base_mint_address: market_info.base_mint_address,
+            quote_mint_address: market_info.quote_mint_address,
+            dex_program: market_info.dex_program,
+            is_enabled,
+            memo: String::from(memo)
+        }
+    );
+}
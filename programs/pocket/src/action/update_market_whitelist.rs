@@ -0,0 +1,113 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from a mint curator.
+#[derive(Accounts)]
+pub struct UpdateMarketWhitelistContext<'info> {
+    // We define the fee payer
+    #[account(
+        mut,
+        constraint = pocket_registry.is_mint_curator(signer.key()) @ PocketError::OnlyMintCurator
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_multisig_enabled() @ PocketError::MultisigRequired
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    /// CHECK: the market state is loaded and verified against the dex program
+    #[account(
+        owner = dex_program.key() @ PocketError::MarketNotAllowed
+    )]
+    pub market_key: AccountInfo<'info>,
+
+    /// CHECK: the dex program which owns the market
+    #[account(executable)]
+    pub dex_program: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> UpdateMarketWhitelistContext<'info> {
+    pub fn add_market(&mut self) -> Result<()> {
+        let (base_mint_address, quote_mint_address) = load_market_mints(
+            &self.market_key,
+            &self.dex_program.key(),
+        ).unwrap();
+
+        self.pocket_registry.add_market(MarketInfo {
+            is_enabled: true,
+            market_key: self.market_key.key(),
+            base_mint_address,
+            quote_mint_address,
+            dex_program: self.dex_program.key(),
+        }).unwrap();
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        self.emit_event("MARKET_ADDED");
+
+        Ok(())
+    }
+
+    pub fn enable_market(&mut self) -> Result<()> {
+        self.pocket_registry.set_market_enabled(self.market_key.key(), true).unwrap();
+        self.emit_event("MARKET_ENABLED");
+
+        Ok(())
+    }
+
+    pub fn disable_market(&mut self) -> Result<()> {
+        self.pocket_registry.set_market_enabled(self.market_key.key(), false).unwrap();
+        self.emit_event("MARKET_DISABLED");
+
+        Ok(())
+    }
+
+    pub fn remove_market(&mut self) -> Result<()> {
+        let market_info = self.pocket_registry.get_market_info(self.market_key.key())
+            .cloned()
+            .unwrap_or_default();
+
+        self.pocket_registry.remove_market(self.market_key.key()).unwrap();
+
+        pocket_emit!(
+            MarketWhitelistUpdated {
+                actor: self.signer.key(),
+                market_key: self.market_key.key(),
+                base_mint_address: market_info.base_mint_address,
+                quote_mint_address: market_info.quote_mint_address,
+                dex_program: market_info.dex_program,
+                is_enabled: false,
+                memo: String::from("MARKET_REMOVED")
+            }
+        );
+
+        Ok(())
+    }
+
+    fn emit_event(&self, memo: &str) {
+        let market_info = self.pocket_registry.get_market_info(self.market_key.key()).unwrap();
+
+        pocket_emit!(
+            MarketWhitelistUpdated {
+                actor: self.signer.key(),
+                market_key: market_info.market_key,
+                base_mint_address: market_info.base_mint_address,
+                quote_mint_address: market_info.quote_mint_address,
+                dex_program: market_info.dex_program,
+                is_enabled: market_info.is_enabled,
+                memo: String::from(memo)
+            }
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from a mint curator.
+#[derive(Accounts)]
+pub struct UpdateMintWhitelistContext<'info> {
+    // We define the fee payer
+    #[account(
+        mut,
+        constraint = pocket_registry.is_mint_curator(signer.key()) @ PocketError::OnlyMintCurator
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_multisig_enabled() @ PocketError::MultisigRequired
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> UpdateMintWhitelistContext<'info> {
+    pub fn add_mint_account(&mut self) -> Result<()> {
+        self.pocket_registry.add_mint_account(self.mint_account.key()).unwrap();
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        self.emit_event(true, "MINT_ACCOUNT_ADDED");
+
+        Ok(())
+    }
+
+    pub fn enable_mint_account(&mut self) -> Result<()> {
+        self.pocket_registry.set_mint_account_enabled(self.mint_account.key(), true).unwrap();
+        self.emit_event(true, "MINT_ACCOUNT_ENABLED");
+
+        Ok(())
+    }
+
+    pub fn disable_mint_account(&mut self) -> Result<()> {
+        self.pocket_registry.set_mint_account_enabled(self.mint_account.key(), false).unwrap();
+        self.emit_event(false, "MINT_ACCOUNT_DISABLED");
+
+        Ok(())
+    }
+
+    pub fn remove_mint_account(&mut self) -> Result<()> {
+        self.pocket_registry.remove_mint_account(self.mint_account.key()).unwrap();
+        self.emit_event(false, "MINT_ACCOUNT_REMOVED");
+
+        Ok(())
+    }
+
+    fn emit_event(&self, is_enabled: bool, memo: &str) {
+        pocket_emit!(
+            MintWhitelistUpdated {
+                actor: self.signer.key(),
+                mint_account: self.mint_account.key(),
+                is_enabled,
+                memo: String::from(memo)
+            }
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct UpdatePlatformFeeParams {
+    pub platform_fee_bps: u64,
+    pub keeper_tip_amount: u64,
+}
+
+// Define the context, passed in parameters when trigger from a fee manager.
+#[derive(Accounts)]
+pub struct UpdatePlatformFeeContext<'info> {
+    // We define the fee payer
+    #[account(
+        mut,
+        constraint = pocket_registry.is_fee_manager(signer.key()) @ PocketError::OnlyFeeManager
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_timelock_enabled() @ PocketError::TimelockRequired
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> UpdatePlatformFeeContext<'info> {
+    pub fn execute(&mut self, params: UpdatePlatformFeeParams) -> Result<()> {
+        let pocket_registry = &mut self.pocket_registry;
+
+        pocket_registry.apply_admin_action(&AdminAction::UpdatePlatformFee {
+            platform_fee_bps: params.platform_fee_bps,
+            keeper_tip_amount: params.keeper_tip_amount
+        }).unwrap();
+
+        pocket_emit!(
+            PlatformFeeUpdated {
+                actor: self.signer.key(),
+                platform_fee_bps: params.platform_fee_bps,
+                keeper_tip_amount: params.keeper_tip_amount
+            }
+        );
+
+        Ok(())
+    }
+}
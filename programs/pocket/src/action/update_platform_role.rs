@@ -0,0 +1,79 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct UpdatePlatformRoleParams {
+    pub role: PlatformRole,
+    pub account: Pubkey,
+}
+
+// Define the context, passed in parameters when trigger from an admin.
+// The roles are granted through the admin changes once the timelock or the multisig is enabled.
+#[derive(Accounts)]
+pub struct UpdatePlatformRoleContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_admin(signer.key()) @ PocketError::OnlyAdministrator
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_timelock_enabled() @ PocketError::TimelockRequired,
+        constraint = !pocket_registry.is_multisig_enabled() @ PocketError::MultisigRequired
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> UpdatePlatformRoleContext<'info> {
+    pub fn grant_role(&mut self, params: UpdatePlatformRoleParams) -> Result<()> {
+        self.ensure_able_to_manage(params.role).unwrap();
+        self.pocket_registry.grant_role(params.role, params.account).unwrap();
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
+
+        pocket_emit!(
+            RoleGranted {
+                actor: self.signer.key(),
+                role: params.role,
+                account: params.account
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn revoke_role(&mut self, params: UpdatePlatformRoleParams) -> Result<()> {
+        self.ensure_able_to_manage(params.role).unwrap();
+        self.pocket_registry.revoke_role(params.role, params.account).unwrap();
+
+        pocket_emit!(
+            RoleRevoked {
+                actor: self.signer.key(),
+                role: params.role,
+                account: params.account
+            }
+        );
+
+        Ok(())
+    }
+
+    // Only the owner can manage the admins
+    fn ensure_able_to_manage(&self, role: PlatformRole) -> Result<()> {
+        if role == PlatformRole::Admin && self.pocket_registry.owner != self.signer.key() {
+            return Err(PocketError::OnlyOwner.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from a pauser.
+#[derive(Accounts)]
+pub struct UpdatePlatformStatusContext<'info> {
+    #[account(
+        mut,
+        constraint = pocket_registry.is_pauser(signer.key()) @ PocketError::OnlyPauser
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+        constraint = !pocket_registry.is_multisig_enabled() @ PocketError::MultisigRequired
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+// implement the handler
+impl<'info> UpdatePlatformStatusContext<'info> {
+    pub fn pause(&mut self) -> Result<()> {
+        assert_eq!(self.pocket_registry.is_paused, false, "PLATFORM_ALREADY_PAUSED");
+        self.set_paused(true)
+    }
+
+    pub fn unpause(&mut self) -> Result<()> {
+        assert_eq!(self.pocket_registry.is_paused, true, "PLATFORM_NOT_PAUSED");
+        self.set_paused(false)
+    }
+
+    fn set_paused(&mut self, is_paused: bool) -> Result<()> {
+        let pocket_registry = &mut self.pocket_registry;
+        pocket_registry.is_paused = is_paused;
+
+        pocket_emit!(
+            PlatformStatusUpdated {
+                actor: self.signer.key(),
+                is_paused
+            }
+        );
+
+        Ok(())
+    }
+}
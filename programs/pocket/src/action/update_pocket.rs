@@ -2,7 +2,46 @@ use crate::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
 pub struct UpdatePocketParams {
-    status: PocketStatus
+    status: Option<PocketStatus>,
+
+    // The strategy can only be edited while the pocket is paused
+    name: Option<String>,
+    batch_volume: Option<u64>,
+    start_at: Option<u64>,
+    frequency: Option<DateDuration>,
+    buy_condition: Option<PriceCondition>,
+    remove_buy_condition: bool,
+    stop_conditions: Option<Vec<StopCondition>>,
+
+    // Restart the pocket on deposit once its balance ran out
+    auto_restart_on_deposit: Option<bool>,
+
+    // The schedule settings are edited along with the strategy
+    schedule_mode: Option<ScheduleMode>,
+    catch_up_policy: Option<CatchUpPolicy>,
+    execution_windows: Option<Vec<ExecutionWindow>>,
+
+    // The pocket waits for the start condition, removing it starts the waiting pocket
+    start_condition: Option<PriceCondition>,
+    remove_start_condition: bool,
+}
+
+impl UpdatePocketParams {
+    // Check whether the params edit the pocket strategy
+    pub fn is_editing_strategy(&self) -> bool {
+        return self.name.is_some()
+            || self.batch_volume.is_some()
+            || self.start_at.is_some()
+            || self.frequency.is_some()
+            || self.schedule_mode.is_some()
+            || self.catch_up_policy.is_some()
+            || self.execution_windows.is_some()
+            || self.buy_condition.is_some()
+            || self.remove_buy_condition
+            || self.stop_conditions.is_some()
+            || self.start_condition.is_some()
+            || self.remove_start_condition;
+    }
 }
 
 #[derive(Accounts)]
@@ -12,12 +51,25 @@ pub struct UpdatePocketContext<'info> {
 
     #[account(
         mut,
-        seeds = [POCKET_SEED, pocket.id.as_bytes().as_ref()],
-        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner,
-        bump = pocket.bump,
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress,
+        constraint = pocket.is_owner_or_delegate(signer.key()) @ PocketError::OnlyOwnerOrDelegate,
     )]
     pub pocket: Account<'info, Pocket>,
 
+    /// CHECK: the index of the owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = pocket_registry.bump,
+    )]
+    pub pocket_registry: Account<'info, PocketPlatformRegistry>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -27,28 +79,115 @@ pub struct UpdatePocketContext<'info> {
 
 impl<'info> UpdatePocketContext<'info> {
     pub fn execute(&mut self, params: UpdatePocketParams) -> Result<()> {
+        // Pockets can only be closed while the platform is paused, so that the funds can be withdrawn
+        if self.pocket_registry.is_paused
+            && (params.status != Some(PocketStatus::Closed)
+                || params.is_editing_strategy()
+                || params.auto_restart_on_deposit.is_some()) {
+            return Err(PocketError::PlatformPaused.into());
+        }
+
+        self.ensure_permission(params.clone()).unwrap();
+
+        // The closed pocket only reopens with stop conditions other than the ones it was closed with
+        let has_new_stop_conditions = match &params.stop_conditions {
+            Some(stop_conditions) => !stop_conditions.is_empty() && *stop_conditions != self.pocket.stop_conditions,
+            None => false,
+        };
+
+        // The strategy is edited before the status changes, so that a paused pocket can be edited and restarted at once
+        if params.is_editing_strategy() {
+            self.update_strategy(params.clone()).unwrap();
+        }
+
+        if params.auto_restart_on_deposit.is_some() {
+            self.update_auto_restart(params.auto_restart_on_deposit.unwrap()).unwrap();
+        }
+
+        if params.status.is_some() {
+            self.update_status(params.status.unwrap(), has_new_stop_conditions).unwrap();
+        }
+
+        // A waiting pocket may have been started by the strategy edit as well
+        sync_indexed_pocket_status(&self.pocket_owner_index, self.pocket.key(), self.pocket.status).unwrap();
+
+        Ok(())
+    }
+
+    // The delegates can pause, resume and edit the pocket, only the owner can close and reopen it
+    fn ensure_permission(&self, params: UpdatePocketParams) -> Result<()> {
+        let signer = self.signer.key();
+        let pocket = &self.pocket;
+
+        let is_editing = params.is_editing_strategy() || params.auto_restart_on_deposit.is_some();
+
+        let is_allowed = match params.status {
+            Some(PocketStatus::Active) if pocket.is_able_to_reopen() => pocket.owner == signer,
+            Some(PocketStatus::Active) | Some(PocketStatus::Paused) => pocket.has_permission(signer, DelegatePermission::UpdateStatus),
+            Some(_) => pocket.owner == signer,
+            None => true,
+        } && (!is_editing || pocket.has_permission(signer, DelegatePermission::Edit));
+
+        if !is_allowed {
+            return Err(PocketError::OnlyOwnerOrDelegate.into());
+        }
+
+        Ok(())
+    }
+
+    fn update_auto_restart(&mut self, auto_restart_on_deposit: bool) -> Result<()> {
+        let pocket = &mut self.pocket;
+
+        assert_ne!(pocket.status, PocketStatus::Withdrawn, "COULD_NOT_EDIT_POCKET");
+
+        pocket.auto_restart_on_deposit = auto_restart_on_deposit;
+
+        pocket_emit!(
+            PocketUpdated {
+                actor: self.signer.key(),
+                pocket_address: pocket.key(),
+                status: pocket.status,
+                memo: String::from("USER_UPDATED_AUTO_RESTART")
+            }
+        );
+
+        Ok(())
+    }
+
+    fn update_status(&mut self, status: PocketStatus, has_new_stop_conditions: bool) -> Result<()> {
         let pocket = &mut self.pocket;
 
-        match params.status {
+        match status {
+            // The closed pocket runs again once new stop conditions are given
+            PocketStatus::Active if pocket.is_able_to_reopen() => {
+                let now = Clock::get().unwrap().unix_timestamp as u64;
+
+                assert_eq!(has_new_stop_conditions, true, "STOP_CONDITIONS_REQUIRED");
+                assert_eq!(pocket.is_stop_condition_reached(now), false, "STOP_CONDITION_ALREADY_REACHED");
+
+                pocket.reopen(now);
+            },
+
             PocketStatus::Active => {
                 assert_eq!(pocket.is_able_to_restart(), true, "COULD_NOT_RESTART_POCKET");
+                pocket.restart(Clock::get().unwrap().unix_timestamp as u64);
             },
 
             PocketStatus::Paused => {
                 assert_eq!(pocket.is_able_to_pause(), true, "COULD_NOT_PAUSE_POCKET");
+                pocket.status = status;
             },
 
             PocketStatus::Closed => {
                 assert_eq!(pocket.is_able_to_close(), true, "COULD_NOT_CLOSE_POCKET");
+                pocket.mark_closed(PocketCloseReason::User);
             },
 
-            PocketStatus::Withdrawn => {
+            PocketStatus::Withdrawn | PocketStatus::Waiting => {
                 assert_eq!(1, 0, "INVALID_INPUT");
             }
         }
 
-        pocket.status = params.status;
-
         pocket_emit!(
             PocketUpdated {
                 actor: self.signer.key(),
@@ -60,4 +199,98 @@ impl<'info> UpdatePocketContext<'info> {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    fn update_strategy(&mut self, params: UpdatePocketParams) -> Result<()> {
+        let pocket = &mut self.pocket;
+
+        assert_eq!(pocket.is_able_to_edit(), true, "COULD_NOT_EDIT_POCKET");
+
+        let before = pocket.get_strategy();
+
+        // the next execution follows the edited schedule
+        let is_editing_schedule = params.start_at.is_some()
+            || params.frequency.is_some()
+            || params.schedule_mode.is_some()
+            || params.execution_windows.is_some();
+
+        if params.name.is_some() {
+            pocket.name = params.name.unwrap();
+        }
+
+        if params.batch_volume.is_some() {
+            pocket.batch_volume = params.batch_volume.unwrap();
+        }
+
+        if params.start_at.is_some() {
+            pocket.start_at = params.start_at.unwrap();
+            pocket.validate_start_at().unwrap();
+        }
+
+        if params.frequency.is_some() {
+            pocket.frequency = params.frequency;
+        }
+
+        if params.schedule_mode.is_some() {
+            pocket.schedule_mode = params.schedule_mode.unwrap();
+        }
+
+        if params.catch_up_policy.is_some() {
+            pocket.catch_up_policy = params.catch_up_policy.unwrap();
+        }
+
+        // the empty list removes the execution windows
+        if params.execution_windows.is_some() {
+            pocket.execution_windows = params.execution_windows.unwrap();
+        }
+
+        if params.remove_buy_condition {
+            pocket.buy_condition = None;
+        } else if params.buy_condition.is_some() {
+            pocket.buy_condition = params.buy_condition;
+        }
+
+        if params.stop_conditions.is_some() {
+            pocket.stop_conditions = params.stop_conditions.unwrap();
+        }
+
+        if params.remove_start_condition {
+            pocket.start_condition = None;
+        } else if params.start_condition.is_some() {
+            pocket.start_condition = params.start_condition;
+        }
+
+        if is_editing_schedule {
+            pocket.reschedule(Clock::get().unwrap().unix_timestamp as u64);
+        }
+
+        // the waiting pocket starts right away once its start condition is removed
+        if pocket.status == PocketStatus::Waiting && pocket.start_condition.is_none() {
+            pocket.start(Clock::get().unwrap().unix_timestamp as u64);
+        }
+
+        // must check for valid data
+        pocket.validate_pocket_data().unwrap();
+
+        // the pocket grows when the name or the stop conditions are longer
+        let space = pocket.current_space();
+        let keeper_tip_balance = pocket.keeper_tip_balance;
+        realloc_account(
+            &self.pocket.to_account_info(),
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+            space,
+            keeper_tip_balance,
+        ).unwrap();
+
+        pocket_emit!(
+            PocketStrategyUpdated {
+                actor: self.signer.key(),
+                pocket_address: self.pocket.key(),
+                before,
+                after: self.pocket.get_strategy()
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct SetPocketDelegateParams {
+    pub delegate: Pubkey,
+    pub permissions: DelegatePermissions,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct RemovePocketDelegateParams {
+    pub delegate: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePocketDelegateContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = pocket.derive_address() @ PocketError::InvalidPocketAddress,
+        constraint = pocket.owner == signer.key() @ PocketError::OnlyOwner,
+    )]
+    pub pocket: Account<'info, Pocket>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdatePocketDelegateContext<'info> {
+    pub fn set_delegate(&mut self, params: SetPocketDelegateParams) -> Result<()> {
+        assert_ne!(params.delegate, Pubkey::default(), "DELEGATE_IS_NOT_VALID");
+        assert_ne!(params.delegate, self.pocket.owner, "DELEGATE_IS_NOT_VALID");
+
+        self.pocket.set_delegate(params.delegate, params.permissions).unwrap();
+
+        // the pocket grows when a new delegate is added
+        let space = self.pocket.current_space();
+        realloc_account(
+            &self.pocket.to_account_info(),
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+            space,
+            self.pocket.keeper_tip_balance,
+        ).unwrap();
+
+        pocket_emit!(
+            PocketDelegateUpdated {
+                actor: self.signer.key(),
+                pocket_address: self.pocket.key(),
+                delegate: params.delegate,
+                permissions: params.permissions
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn remove_delegate(&mut self, params: RemovePocketDelegateParams) -> Result<()> {
+        self.pocket.remove_delegate(params.delegate).unwrap();
+
+        pocket_emit!(
+            PocketDelegateRemoved {
+                actor: self.signer.key(),
+                pocket_address: self.pocket.key(),
+                delegate: params.delegate
+            }
+        );
+
+        Ok(())
+    }
+}
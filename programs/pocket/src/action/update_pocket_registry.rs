@@ -6,21 +6,22 @@ pub struct UpdatePocketRegistryParams {
     pub operators: Vec<Pubkey>,
 }
 
-// Define the context, passed in parameters when trigger from deployer.
+// Define the context, passed in parameters when trigger from an admin.
 #[derive(Accounts)]
 pub struct UpdatePocketRegistryContext<'info> {
     // We define the fee payer
     #[account(
         mut,
-        address = pocket_registry.owner @ PocketError::OnlyAdministrator
+        constraint = pocket_registry.is_admin(signer.key()) @ PocketError::OnlyAdministrator
     )]
-    pub owner: Signer<'info>,
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
         seeds = [PLATFORM_SEED],
         bump = pocket_registry.bump,
-        has_one = owner
+        constraint = !pocket_registry.is_timelock_enabled() @ PocketError::TimelockRequired,
+        constraint = !pocket_registry.is_multisig_enabled() @ PocketError::MultisigRequired
     )]
     pub pocket_registry: Account<'info, PocketPlatformRegistry>,
 
@@ -32,12 +33,20 @@ pub struct UpdatePocketRegistryContext<'info> {
 impl<'info> UpdatePocketRegistryContext<'info> {
     pub fn execute(&mut self, params: UpdatePocketRegistryParams) -> Result<()> {
         let pocket_registry = &mut self.pocket_registry;
-        
-        pocket_registry.operators = params.operators.clone();
+
+        pocket_registry.apply_admin_action(&AdminAction::UpdateOperators {
+            operators: params.operators.clone()
+        }).unwrap();
+
+        realloc_account_to_fit(
+            &self.pocket_registry,
+            &self.signer.to_account_info(),
+            &self.system_program.to_account_info(),
+        ).unwrap();
 
         pocket_emit!(
             PocketConfigUpdated {
-                actor: self.owner.key(),
+                actor: self.signer.key(),
                 operators: params.operators.clone()
             }
         );
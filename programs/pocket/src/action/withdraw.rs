@@ -1,5 +1,23 @@
 use crate::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub enum WithdrawMode {
+    // Withdraw both vaults of a closed pocket
+    #[default]
+    Full,
+
+    // Withdraw an amount of the base or quote token, the pocket keeps running
+    Partial {
+        mint_address: Pubkey,
+        amount: u64,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct WithdrawParams {
+    pub mode: WithdrawMode,
+}
+
 #[derive(Accounts)]
 pub struct WithdrawContext<'info> {
     #[account(mut)]
@@ -11,18 +29,33 @@ pub struct WithdrawContext<'info> {
     )]
     pub pocket: Account<'info, Pocket>,
 
+    /// CHECK: the index of the owner, skipped when the owner never created one
+    #[account(
+        mut,
+        seeds = [POCKET_OWNER_INDEX_SEED, pocket.owner.as_ref()],
+        bump
+    )]
+    pub pocket_owner_index: AccountInfo<'info>,
+
+    // The recipients are chosen by the owner, and only checked against the mints
+    /// CHECK: the token account is only loaded and verified when the base token is withdrawn
     #[account(mut)]
-    /// CHECK: the signer token account can be verified later
     pub signer_base_token_account: AccountInfo<'info>,
 
+    /// CHECK: the token account is only loaded and verified when the quote token is withdrawn
     #[account(mut)]
-    /// CHECK: the signer token account can be verified later
     pub signer_quote_token_account: AccountInfo<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = Pocket::find_token_vault_address(pocket.key(), pocket.base_token_mint_address) @ PocketError::InvalidTokenVault
+    )]
     pub pocket_base_token_vault: Account<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = Pocket::find_token_vault_address(pocket.key(), pocket.quote_token_mint_address) @ PocketError::InvalidTokenVault
+    )]
     pub pocket_quote_token_vault: Account<'info, TokenAccount>,
 
     #[account(address = system_program::ID)]
@@ -33,54 +66,104 @@ pub struct WithdrawContext<'info> {
 }
 
 impl<'info> WithdrawContext<'info> {
-    pub fn execute(&mut self) -> Result<()> {
-        let pocket = &mut self.pocket;
+    pub fn execute(&mut self, params: WithdrawParams) -> Result<()> {
+        return match params.mode {
+            WithdrawMode::Full => {
+                self.withdraw_fully().unwrap();
+                sync_indexed_pocket_status(&self.pocket_owner_index, self.pocket.key(), self.pocket.status)
+            }
 
-        assert_eq!(pocket.is_able_to_withdraw(), true, "NOT_ABLE_TO_WITHDRAW");
+            WithdrawMode::Partial { mint_address, amount } => self.withdraw_partially(mint_address, amount),
+        };
+    }
 
-        let pocket_base_token_vault = &self.pocket_base_token_vault;
-        let pocket_quote_token_vault = &self.pocket_quote_token_vault;
+    fn withdraw_partially(&mut self, mint_address: Pubkey, amount: u64) -> Result<()> {
+        let pocket = &mut self.pocket;
 
-        let signer_base_token_vault = &self.signer_base_token_account;
-        let signer_quote_token_vault = &self.signer_quote_token_account;
+        assert_eq!(pocket.is_able_to_withdraw_partially(), true, "NOT_ABLE_TO_WITHDRAW");
 
-        // update credited balance & status
-        pocket.base_token_balance = 0;
-        pocket.quote_token_balance = 0;
-        pocket.status = PocketStatus::Withdrawn;
+        // update credited balance, the status is left untouched
+        pocket.withdraw_partially(mint_address, amount).unwrap();
 
-        // find the bump to sign with the pda
-        let bump = &[pocket.bump][..];
-        let signer = &[&[POCKET_SEED, pocket.id.as_bytes().as_ref(), bump][..]];
+        let is_base_token = mint_address == pocket.base_token_mint_address;
 
-        // transfer the token
-        token::transfer(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                Transfer {
-                    from: pocket_base_token_vault.to_account_info(),
-                    to: signer_base_token_vault.to_account_info(),
-                    authority: pocket.to_account_info(),
-                },
-                signer,
-            ),
-            self.pocket_base_token_vault.amount,
+        let pocket_token_vault = if is_base_token { &self.pocket_base_token_vault } else { &self.pocket_quote_token_vault };
+        let signer_token_account = load_signer_token_account(
+            if is_base_token { &self.signer_base_token_account } else { &self.signer_quote_token_account },
+            mint_address,
         ).unwrap();
 
+        // find the seeds to sign with the pda
+        pocket_signer!(pocket, signer);
+
         // transfer the token
         token::transfer(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
                 Transfer {
-                    from: pocket_quote_token_vault.to_account_info(),
-                    to: signer_quote_token_vault.to_account_info(),
+                    from: pocket_token_vault.to_account_info(),
+                    to: signer_token_account.to_account_info(),
                     authority: pocket.to_account_info(),
                 },
                 signer,
             ),
-            self.pocket_quote_token_vault.amount,
+            amount,
         ).unwrap();
 
+        // emit event
+        pocket_emit!(
+            PocketWithdrawn {
+               owner: self.signer.key(),
+               pocket_address: pocket.key(),
+               base_token_mint_address: pocket.base_token_mint_address,
+               base_token_amount: if is_base_token { amount } else { 0 },
+               quote_token_mint_address: pocket.quote_token_mint_address,
+               quote_token_amount: if is_base_token { 0 } else { amount }
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw_fully(&mut self) -> Result<()> {
+        let pocket = &mut self.pocket;
+
+        assert_eq!(pocket.is_able_to_withdraw(), true, "NOT_ABLE_TO_WITHDRAW");
+
+        // update credited balance & status
+        pocket.base_token_balance = 0;
+        pocket.quote_token_balance = 0;
+        pocket.status = PocketStatus::Withdrawn;
+
+        // find the seeds to sign with the pda
+        pocket_signer!(pocket, signer);
+
+        // the empty vaults are skipped, so that only the token accounts of the withdrawn mints are required
+        for (pocket_token_vault, signer_token_account) in [
+            (&self.pocket_base_token_vault, &self.signer_base_token_account),
+            (&self.pocket_quote_token_vault, &self.signer_quote_token_account),
+        ] {
+            if pocket_token_vault.amount == 0 {
+                continue;
+            }
+
+            let signer_token_account = load_signer_token_account(signer_token_account, pocket_token_vault.mint).unwrap();
+
+            // transfer the token
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: pocket_token_vault.to_account_info(),
+                        to: signer_token_account.to_account_info(),
+                        authority: pocket.to_account_info(),
+                    },
+                    signer,
+                ),
+                pocket_token_vault.amount,
+            ).unwrap();
+        }
+
         // emit event
         pocket_emit!(
             PocketWithdrawn {
@@ -95,4 +178,19 @@ impl<'info> WithdrawContext<'info> {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+// Load the recipient token account, it must hold the withdrawn mint
+fn load_signer_token_account<'info>(
+    signer_token_account: &AccountInfo<'info>,
+    mint_address: Pubkey,
+) -> Result<Account<'info, TokenAccount>> {
+    let signer_token_account: Account<TokenAccount> = Account::try_from(signer_token_account).unwrap();
+
+    if signer_token_account.mint != mint_address {
+        msg!("ERROR::POCKET::INVALID_TOKEN_ACCOUNT");
+        return Err(PocketError::InvalidTokenAccount.into());
+    }
+
+    Ok(signer_token_account)
+}
@@ -4,3 +4,46 @@ pub const PLATFORM_SEED: &[u8] = b"SEED::POCKET::PLATFORM";
 pub const TOKEN_ACCOUNT_SEED: &[u8] = b"SEED::POCKET::TOKEN_VAULT_SEED";
 pub const POCKET_SEED: &[u8] = b"SEED::POCKET::POCKET_SEED";
 pub const LOOKUP_TABLE_SEED: &[u8] = b"SEED::POCKET::LOOKUP_TABLE_SEED";
+pub const TREASURY_SEED: &[u8] = b"SEED::POCKET::TREASURY_SEED";
+pub const MULTISIG_PROPOSAL_SEED: &[u8] = b"SEED::POCKET::MULTISIG_PROPOSAL_SEED";
+pub const OPEN_ORDERS_SEED: &[u8] = b"SEED::POCKET::OPEN_ORDERS_SEED";
+pub const POCKET_NONCE_SEED: &[u8] = b"SEED::POCKET::POCKET_NONCE_SEED";
+pub const POCKET_OWNER_INDEX_SEED: &[u8] = b"SEED::POCKET::POCKET_OWNER_INDEX_SEED";
+
+// Size of a dex open orders account, including the 12 bytes of account padding
+pub const OPEN_ORDERS_ACCOUNT_SIZE: usize = 3228;
+
+// Platform fee is defined in basis points
+pub const PLATFORM_FEE_DENOMINATOR: u64 = 10_000;
+pub const MAX_PLATFORM_FEE_BPS: u64 = 1_000;
+
+// Administrative changes can be timelocked for at most 30 days
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 3600;
+
+// The multisig can have at most 10 signers
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+// Account layout versions, the v1 accounts were created before the version field existed
+pub const POCKET_VERSION: u8 = 11;
+pub const REGISTRY_VERSION: u8 = 2;
+
+// Allocated space of the v1 pocket and registry accounts
+pub const LEGACY_POCKET_ACCOUNT_SIZE: usize = 10240;
+pub const LEGACY_REGISTRY_ACCOUNT_SIZE: usize = 10240;
+
+// Pocket data limits, the pocket id is used as a PDA seed which is at most 32 bytes
+pub const MAX_POCKET_ID_LENGTH: usize = 32;
+pub const MAX_POCKET_NAME_LENGTH: usize = 64;
+pub const MAX_STOP_CONDITIONS: usize = 10;
+pub const MAX_POCKET_DELEGATES: usize = 5;
+pub const MAX_EXECUTION_WINDOWS: usize = 4;
+
+// Owner index limits
+pub const MAX_INDEXED_POCKETS: usize = 128;
+
+// Platform registry limits
+pub const MAX_OPERATORS: usize = 16;
+pub const MAX_ROLE_MEMBERS: usize = 16;
+pub const MAX_ALLOWED_MINT_ACCOUNTS: usize = 64;
+pub const MAX_ALLOWED_MARKETS: usize = 64;
+pub const MAX_QUEUED_CHANGES: usize = 16;
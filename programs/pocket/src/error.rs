@@ -23,5 +23,140 @@ pub enum PocketError {
     OnlyOwner,
 
     #[msg("Not ready to swap")]
-    NotReadyToSwap
+    NotReadyToSwap,
+
+    #[msg("The mint account was not existed")]
+    MintAccountNotExisted,
+
+    #[msg("The mint account is not allowed")]
+    MintAccountNotAllowed,
+
+    #[msg("The market was existed")]
+    MarketExisted,
+
+    #[msg("The market was not existed")]
+    MarketNotExisted,
+
+    #[msg("The market is not allowed")]
+    MarketNotAllowed,
+
+    #[msg("The market does not match the token pair")]
+    MarketMintMismatched,
+
+    #[msg("The platform fee is not valid")]
+    InvalidFeeRate,
+
+    #[msg("The treasury vault is not valid")]
+    InvalidTreasuryVault,
+
+    #[msg("The token account is not valid")]
+    InvalidTokenAccount,
+
+    #[msg("Only Pending Owner")]
+    OnlyPendingOwner,
+
+    #[msg("Only Platform Pauser")]
+    OnlyPauser,
+
+    #[msg("Only Platform Fee Manager")]
+    OnlyFeeManager,
+
+    #[msg("Only Platform Mint Curator")]
+    OnlyMintCurator,
+
+    #[msg("The role was already granted")]
+    RoleExisted,
+
+    #[msg("The role was not granted")]
+    RoleNotExisted,
+
+    #[msg("The platform is paused")]
+    PlatformPaused,
+
+    #[msg("The change must be queued through the timelock")]
+    TimelockRequired,
+
+    #[msg("The timelock has not expired yet")]
+    TimelockNotExpired,
+
+    #[msg("The queued change was not existed")]
+    QueuedChangeNotExisted,
+
+    #[msg("Timelock delay is not valid")]
+    InvalidTimelockDelay,
+
+    #[msg("The change must be approved through the multisig")]
+    MultisigRequired,
+
+    #[msg("The multisig is not enabled")]
+    MultisigNotEnabled,
+
+    #[msg("Only Multisig Signer")]
+    OnlyMultisigSigner,
+
+    #[msg("Multisig config is not valid")]
+    InvalidMultisigConfig,
+
+    #[msg("The proposal was already approved by the signer")]
+    ProposalAlreadyApproved,
+
+    #[msg("The proposal has not reached the threshold yet")]
+    NotEnoughApprovals,
+
+    #[msg("Only Proposer")]
+    OnlyProposer,
+
+    #[msg("The account was already migrated")]
+    AlreadyMigrated,
+
+    #[msg("Pocket id is too long")]
+    PocketIdTooLong,
+
+    #[msg("Pocket name is too long")]
+    PocketNameTooLong,
+
+    #[msg("Too many stop conditions")]
+    TooManyStopConditions,
+
+    #[msg("Too many operators")]
+    TooManyOperators,
+
+    #[msg("Too many role members")]
+    TooManyRoleMembers,
+
+    #[msg("Too many whitelisted mint accounts")]
+    TooManyMintAccounts,
+
+    #[msg("Too many whitelisted markets")]
+    TooManyMarkets,
+
+    #[msg("Too many queued changes")]
+    TooManyQueuedChanges,
+
+    #[msg("Only Owner Or Delegate")]
+    OnlyOwnerOrDelegate,
+
+    #[msg("Too many delegates")]
+    TooManyDelegates,
+
+    #[msg("The delegate was not existed")]
+    DelegateNotExisted,
+
+    #[msg("The token vault is not valid")]
+    InvalidTokenVault,
+
+    #[msg("The pocket address is not valid")]
+    InvalidPocketAddress,
+
+    #[msg("The pocket id must only contain letters, digits, dashes and underscores")]
+    InvalidPocketId,
+
+    #[msg("Too many execution windows")]
+    TooManyExecutionWindows,
+
+    #[msg("The start condition is not reached")]
+    StartConditionNotReached,
+
+    #[msg("The open orders account is not valid")]
+    InvalidOpenOrders
 }
@@ -18,6 +18,192 @@ pub struct PocketConfigUpdated {
     pub operators: Vec<Pubkey>,
 }
 
+/// Emitted when a [PlatformStatusUpdated] is created.
+#[event]
+pub struct PlatformStatusUpdated {
+    #[index]
+    pub actor: Pubkey,
+    pub is_paused: bool,
+}
+
+/// Emitted when a [AdminChangeQueued] is created.
+#[event]
+pub struct AdminChangeQueued {
+    #[index]
+    pub actor: Pubkey,
+    pub change_id: u64,
+    pub action: AdminAction,
+    pub eta: u64,
+}
+
+/// Emitted when a [AdminChangeExecuted] is created.
+#[event]
+pub struct AdminChangeExecuted {
+    #[index]
+    pub actor: Pubkey,
+    pub change_id: u64,
+    pub action: AdminAction,
+}
+
+/// Emitted when a [AdminChangeCancelled] is created.
+#[event]
+pub struct AdminChangeCancelled {
+    #[index]
+    pub actor: Pubkey,
+    pub change_id: u64,
+    pub action: AdminAction,
+}
+
+/// Emitted when a [MultisigActionProposed] is created.
+#[event]
+pub struct MultisigActionProposed {
+    #[index]
+    pub actor: Pubkey,
+    pub proposal_id: u64,
+    pub action: AdminAction,
+}
+
+/// Emitted when a [MultisigActionApproved] is created.
+#[event]
+pub struct MultisigActionApproved {
+    #[index]
+    pub actor: Pubkey,
+    pub proposal_id: u64,
+    pub approvals: Vec<Pubkey>,
+}
+
+/// Emitted when a [MultisigActionExecuted] is created.
+#[event]
+pub struct MultisigActionExecuted {
+    #[index]
+    pub actor: Pubkey,
+    pub proposal_id: u64,
+    pub action: AdminAction,
+}
+
+/// Emitted when a [MultisigActionCancelled] is created.
+#[event]
+pub struct MultisigActionCancelled {
+    #[index]
+    pub actor: Pubkey,
+    pub proposal_id: u64,
+}
+
+/// Emitted when a [AccountMigrated] is created.
+#[event]
+pub struct AccountMigrated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub account: Pubkey,
+    pub previous_version: u8,
+    pub version: u8,
+}
+
+/// Emitted when a [RoleGranted] is created.
+#[event]
+pub struct RoleGranted {
+    #[index]
+    pub actor: Pubkey,
+    pub role: PlatformRole,
+    #[index]
+    pub account: Pubkey,
+}
+
+/// Emitted when a [RoleRevoked] is created.
+#[event]
+pub struct RoleRevoked {
+    #[index]
+    pub actor: Pubkey,
+    pub role: PlatformRole,
+    #[index]
+    pub account: Pubkey,
+}
+
+/// Emitted when a [RegistryOwnerProposed] is created.
+#[event]
+pub struct RegistryOwnerProposed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pending_owner: Pubkey,
+}
+
+/// Emitted when a [RegistryOwnerTransferred] is created.
+#[event]
+pub struct RegistryOwnerTransferred {
+    #[index]
+    pub previous_owner: Pubkey,
+    #[index]
+    pub owner: Pubkey,
+}
+
+/// Emitted when a [MintWhitelistUpdated] is created.
+#[event]
+pub struct MintWhitelistUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub mint_account: Pubkey,
+    pub is_enabled: bool,
+    pub memo: String,
+}
+
+/// Emitted when a [MarketWhitelistUpdated] is created.
+#[event]
+pub struct MarketWhitelistUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub market_key: Pubkey,
+    pub base_mint_address: Pubkey,
+    pub quote_mint_address: Pubkey,
+    pub dex_program: Pubkey,
+    pub is_enabled: bool,
+    pub memo: String,
+}
+
+/// Emitted when a [PlatformFeeUpdated] is created.
+#[event]
+pub struct PlatformFeeUpdated {
+    #[index]
+    pub actor: Pubkey,
+    pub platform_fee_bps: u64,
+    pub keeper_tip_amount: u64,
+}
+
+/// Emitted when a [TreasuryVaultCreated] is created.
+#[event]
+pub struct TreasuryVaultCreated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub mint_account: Pubkey,
+    #[index]
+    pub associated_account: Pubkey,
+}
+
+/// Emitted when a [PlatformFeeCharged] is created.
+#[event]
+pub struct PlatformFeeCharged {
+    #[index]
+    pub pocket_address: Pubkey,
+    #[index]
+    pub mint_address: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a [PlatformFeeClaimed] is created.
+#[event]
+pub struct PlatformFeeClaimed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub mint_address: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
 /// Emitted when a [VaultCreated] is created.
 #[event]
 pub struct VaultCreated {
@@ -54,6 +240,97 @@ pub struct PocketUpdated {
     pub memo: String
 }
 
+/// Emitted when a [PocketStrategyUpdated] is created.
+#[event]
+pub struct PocketStrategyUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub before: PocketStrategy,
+    pub after: PocketStrategy,
+}
+
+/// Emitted when a [PocketOwnerProposed] is created.
+#[event]
+pub struct PocketOwnerProposed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+/// Emitted when a [PocketOwnerProposalCancelled] is created.
+#[event]
+pub struct PocketOwnerProposalCancelled {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+/// Emitted when a [PocketOwnerTransferred] is created.
+#[event]
+pub struct PocketOwnerTransferred {
+    #[index]
+    pub pocket_address: Pubkey,
+    pub previous_owner: Pubkey,
+    #[index]
+    pub owner: Pubkey,
+}
+
+/// Emitted when a [PocketDelegateUpdated] is created.
+#[event]
+pub struct PocketDelegateUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: DelegatePermissions,
+}
+
+/// Emitted when a [PocketDelegateRemoved] is created.
+#[event]
+pub struct PocketDelegateRemoved {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// Emitted when a [KeeperTipToppedUp] is created.
+#[event]
+pub struct KeeperTipToppedUp {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a [KeeperTipPaid] is created.
+#[event]
+pub struct KeeperTipPaid {
+    #[index]
+    pub operator: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a [PocketBatchesSkipped] is created.
+#[event]
+pub struct PocketBatchesSkipped {
+    #[index]
+    pub pocket_address: Pubkey,
+    pub skipped_batch_amount: u64,
+    pub total_skipped_batch_amount: u64,
+}
+
 /// Emitted when a [PocketDeposited] is created.
 #[event]
 pub struct PocketDeposited {
@@ -79,4 +356,13 @@ pub struct PocketWithdrawn {
     #[index]
     pub quote_token_mint_address: Pubkey,
     pub quote_token_amount: u64
-}
\ No newline at end of file
+}
+
+/// Emitted when a [PocketIndexSkipped] is created.
+#[event]
+pub struct PocketIndexSkipped {
+    #[index]
+    pub owner: Pubkey,
+    #[index]
+    pub pocket_address: Pubkey,
+}
@@ -15,7 +15,7 @@ use anchor_spl::dex::serum_dex::state::MarketState;
 use anchor_spl::token;
 use std::num::NonZeroU64;
 use anchor_spl::dex::{CloseOpenOrders, InitOpenOrders};
-use crate::{POCKET_SEED, Pocket, pocket_emit};
+use crate::{Pocket, pocket_emit, pocket_signer};
 
 // Associated token account for Pubkey::default.
 mod empty {
@@ -26,6 +26,7 @@ mod empty {
 /// Convenience API to initialize an open orders account on the Serum DEX.
 pub fn init_account(data: &InitAccount) -> Result<()> {
     let pocket = data.pocket.clone();
+    pocket_signer!(pocket, signer);
 
     dex::init_open_orders(CpiContext::new_with_signer(
         data.dex_program.clone(),
@@ -35,11 +36,7 @@ pub fn init_account(data: &InitAccount) -> Result<()> {
             market: data.market_key.to_account_info(),
             rent: data.rent.to_account_info(),
         },
-        &[&[
-            POCKET_SEED,
-            pocket.id.as_bytes().as_ref(),
-            &[pocket.bump],
-        ]],
+        signer,
     )).unwrap();
     Ok(())
 }
@@ -49,6 +46,7 @@ pub fn close_account(
     data: &CloseAccount,
 ) -> Result<()> {
     let pocket = data.pocket.clone();
+    pocket_signer!(pocket, signer);
 
     dex::close_open_orders(
         CpiContext::new_with_signer(
@@ -59,11 +57,7 @@ pub fn close_account(
                 destination: data.destination.to_account_info(),
                 market: data.market_key.to_account_info(),
             },
-            &[&[
-                POCKET_SEED,
-                pocket.id.as_bytes().as_ref(),
-                &[pocket.bump],
-            ]],
+            signer,
         )
     ).unwrap();
     Ok(())
@@ -576,16 +570,13 @@ impl<'info> OrderbookClient<'info> {
         let limit = 65535;
 
         let pocket = self.pocket.clone();
+        pocket_signer!(pocket, signer);
 
         dex::new_order_v3(
             CpiContext::new_with_signer(
                 self.dex_program.clone(),
                 self.clone().into(),
-                &[&[
-                    POCKET_SEED,
-                    pocket.id.as_bytes().as_ref(),
-                    &[pocket.bump],
-                ]],
+                signer,
             ),
             side.into(),
             NonZeroU64::new(limit_price).unwrap(),
@@ -612,15 +603,12 @@ impl<'info> OrderbookClient<'info> {
         };
 
         let pocket = self.pocket.clone();
+        pocket_signer!(pocket, signer);
 
         dex::settle_funds(CpiContext::new_with_signer(
             self.dex_program.clone(),
             settle_accs,
-            &[&[
-                POCKET_SEED,
-                pocket.id.as_bytes().as_ref(),
-                &[pocket.bump],
-            ]],
+            signer,
         ))
     }
 }
@@ -0,0 +1,134 @@
+//! Helpers to read the Serum DEX market state.
+
+use anchor_lang::prelude::*;
+use anchor_spl::dex::serum_dex::critbit::SlabView;
+use anchor_spl::dex::serum_dex::state::MarketState;
+
+use crate::TradeSide;
+
+/// Reads the base (coin) and quote (pc) mint addresses of a market owned by
+/// the given dex program.
+pub fn load_market_mints(market: &AccountInfo, dex_program: &Pubkey) -> Result<(Pubkey, Pubkey)> {
+    // The loaded market must be dropped before the account is used again.
+    let (coin_mint, pc_mint) = {
+        let market_state = MarketState::load(market, dex_program).unwrap();
+        (market_state.coin_mint, market_state.pc_mint)
+    };
+
+    Ok((to_pubkey(coin_mint), to_pubkey(pc_mint)))
+}
+
+/// Quotes the amount received for the volume at the best price of the order
+/// book, the asks are taken when buying and the bids when selling. Returns
+/// `None` when that side of the book is empty or when the best order is too
+/// small to fill the whole volume.
+pub fn load_best_price_quote(
+    market: &AccountInfo,
+    dex_program: &Pubkey,
+    bids: &AccountInfo,
+    asks: &AccountInfo,
+    side: TradeSide,
+    volume: u64,
+) -> Result<Option<u64>> {
+    let market_state = MarketState::load(market, dex_program).unwrap();
+
+    // The best order price is in quote lots per base lot, its quantity in base lots.
+    let best_order = match side {
+        TradeSide::Buy => {
+            let asks = market_state.load_asks_mut(asks).unwrap();
+            asks.find_min().and_then(|handle| asks.get(handle)?.as_leaf().map(|order| (order.price().get(), order.quantity())))
+        }
+
+        TradeSide::Sell => {
+            let bids = market_state.load_bids_mut(bids).unwrap();
+            bids.find_max().and_then(|handle| bids.get(handle)?.as_leaf().map(|order| (order.price().get(), order.quantity())))
+        }
+    };
+
+    let (price, quantity) = match best_order {
+        Some(best_order) => best_order,
+        None => return Ok(None),
+    };
+
+    Ok(quote_order_fill(side, volume, price, quantity, market_state.coin_lot_size, market_state.pc_lot_size))
+}
+
+/// Quotes the amount received when the volume is filled by a single order.
+/// Returns `None` when the order quantity can't fill the whole volume.
+pub fn quote_order_fill(
+    side: TradeSide,
+    volume: u64,
+    price: u64,
+    quantity: u64,
+    coin_lot_size: u64,
+    pc_lot_size: u64,
+) -> Option<u64> {
+    let volume = volume as u128;
+    let price = price as u128;
+    let coin_lot_size = coin_lot_size as u128;
+    let pc_lot_size = pc_lot_size as u128;
+
+    if price == 0 || coin_lot_size == 0 || pc_lot_size == 0 {
+        return None;
+    }
+
+    // The base lots taken from the order, the buy side spends quote and the sell side spends base.
+    let (required_lots, amount) = match side {
+        TradeSide::Buy => (
+            volume / (price * pc_lot_size),
+            volume * coin_lot_size / (price * pc_lot_size),
+        ),
+        TradeSide::Sell => (
+            volume / coin_lot_size,
+            volume * price * pc_lot_size / coin_lot_size,
+        ),
+    };
+
+    if required_lots > quantity as u128 {
+        return None;
+    }
+
+    Some(amount.min(u64::MAX as u128) as u64)
+}
+
+// The dex stores public keys as four little-endian words.
+fn to_pubkey(words: [u64; 4]) -> Pubkey {
+    let mut bytes = [0u8; 32];
+
+    for (index, word) in words.iter().enumerate() {
+        bytes[index * 8..(index + 1) * 8].copy_from_slice(&word.to_le_bytes());
+    }
+
+    Pubkey::new_from_array(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_quote_buy_volume_at_order_price() {
+        // 2 quote lots of 10 per base lot of 100, 1_000 quote buys 50 base lots
+        assert_eq!(quote_order_fill(TradeSide::Buy, 1_000, 2, 50, 100, 10), Some(5_000));
+        assert_eq!(quote_order_fill(TradeSide::Buy, 1_000, 2, 100, 100, 10), Some(5_000));
+    }
+
+    #[test]
+    fn should_quote_sell_volume_at_order_price() {
+        // 1_000 base sells 10 base lots of 100 at 2 quote lots of 10
+        assert_eq!(quote_order_fill(TradeSide::Sell, 1_000, 2, 10, 100, 10), Some(200));
+        assert_eq!(quote_order_fill(TradeSide::Sell, 1_000, 2, 11, 100, 10), Some(200));
+    }
+
+    #[test]
+    fn should_not_quote_when_order_is_too_small() {
+        assert_eq!(quote_order_fill(TradeSide::Buy, 1_000, 2, 49, 100, 10), None);
+        assert_eq!(quote_order_fill(TradeSide::Sell, 1_000, 2, 9, 100, 10), None);
+    }
+
+    #[test]
+    fn should_not_quote_invalid_market() {
+        assert_eq!(quote_order_fill(TradeSide::Buy, 1_000, 0, 50, 100, 10), None);
+        assert_eq!(quote_order_fill(TradeSide::Sell, 1_000, 2, 10, 0, 10), None);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod make_swap;
+pub mod market;
 
-pub use make_swap::*;
\ No newline at end of file
+pub use make_swap::*;
+pub use market::*;
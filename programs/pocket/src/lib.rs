@@ -12,6 +12,7 @@ pub mod state;
 pub mod constants;
 pub mod macros;
 pub mod external;
+pub mod utils;
 
 pub use action::*;
 pub use constants::*;
@@ -20,6 +21,7 @@ pub use state::*;
 pub use event::*;
 pub use macros::*;
 pub use external::*;
+pub use utils::*;
 
 
 declare_id!("BW5RwMCPY85ch6efYE3Ev43ZQpJytvvjSNbJ2beC9MzV");
@@ -54,6 +56,259 @@ pub mod pocket {
         Ok(())
     }
 
+    pub fn grant_role(
+        ctx: Context<UpdatePlatformRoleContext>,
+        params: UpdatePlatformRoleParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.grant_role(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn revoke_role(
+        ctx: Context<UpdatePlatformRoleContext>,
+        params: UpdatePlatformRoleParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.revoke_role(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn pause_platform(
+        ctx: Context<UpdatePlatformStatusContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.pause().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn unpause_platform(
+        ctx: Context<UpdatePlatformStatusContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.unpause().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn queue_admin_change(
+        ctx: Context<AdminChangeContext>,
+        params: QueueAdminChangeParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.queue(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn execute_admin_change(
+        ctx: Context<AdminChangeContext>,
+        params: AdminChangeParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn cancel_admin_change(
+        ctx: Context<AdminChangeContext>,
+        params: AdminChangeParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.cancel(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn propose_multisig_action(
+        ctx: Context<ProposeMultisigActionContext>,
+        params: ProposeMultisigActionParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("multisig_proposal").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn approve_multisig_action(
+        ctx: Context<ApproveMultisigActionContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn execute_multisig_action(
+        ctx: Context<ExecuteMultisigActionContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn cancel_multisig_action(
+        ctx: Context<ExecuteMultisigActionContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.cancel().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn propose_registry_owner(
+        ctx: Context<ProposeRegistryOwnerContext>,
+        params: ProposeRegistryOwnerParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn accept_registry_owner(
+        ctx: Context<AcceptRegistryOwnerContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn add_mint_account(
+        ctx: Context<UpdateMintWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.add_mint_account().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn enable_mint_account(
+        ctx: Context<UpdateMintWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.enable_mint_account().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn disable_mint_account(
+        ctx: Context<UpdateMintWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.disable_mint_account().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn remove_mint_account(
+        ctx: Context<UpdateMintWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.remove_mint_account().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn add_market(
+        ctx: Context<UpdateMarketWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.add_market().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn enable_market(
+        ctx: Context<UpdateMarketWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.enable_market().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn disable_market(
+        ctx: Context<UpdateMarketWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.disable_market().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn remove_market(
+        ctx: Context<UpdateMarketWhitelistContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.remove_market().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn update_platform_fee(
+        ctx: Context<UpdatePlatformFeeContext>,
+        params: UpdatePlatformFeeParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn create_treasury_vault(
+        ctx: Context<CreateTreasuryVaultContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            *ctx.bumps.get("platform_treasury_vault").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn claim_platform_fee(
+        ctx: Context<ClaimPlatformFeeContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
     pub fn create_token_vault(
         ctx: Context<CreateTokenVaultContext>,
     ) -> Result<()> {
@@ -72,6 +327,50 @@ pub mod pocket {
         ctx.accounts.execute(
             params,
             *ctx.bumps.get("pocket").unwrap(),
+            *ctx.bumps.get("pocket_owner_index").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn create_nonce_pocket(
+        ctx: Context<CreateNoncePocketContext>,
+        params: CreatePocketParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("pocket").unwrap(),
+            *ctx.bumps.get("pocket_owner_index").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn sync_pocket_owner_index(
+        ctx: Context<SyncPocketOwnerIndexContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            *ctx.bumps.get("pocket_owner_index").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn open_pocket(
+        ctx: Context<OpenPocketContext>,
+        params: OpenPocketParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("pocket").unwrap(),
+            *ctx.bumps.get("pocket_owner_index").unwrap(),
+            *ctx.bumps.get("open_orders").unwrap(),
         ).unwrap();
 
         // Program result should be ok.
@@ -89,6 +388,54 @@ pub mod pocket {
         Ok(())
     }
 
+    pub fn transfer_pocket_ownership(
+        ctx: Context<TransferPocketOwnershipContext>,
+        params: TransferPocketOwnershipParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("new_pocket_owner_index").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn accept_pocket_ownership(
+        ctx: Context<AcceptPocketOwnershipContext>
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(
+            *ctx.bumps.get("new_pocket_owner_index").unwrap(),
+        ).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn set_pocket_delegate(
+        ctx: Context<UpdatePocketDelegateContext>,
+        params: SetPocketDelegateParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.set_delegate(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn remove_pocket_delegate(
+        ctx: Context<UpdatePocketDelegateContext>,
+        params: RemovePocketDelegateParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.remove_delegate(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
     pub fn deposit(
         ctx: Context<DepositContext>,
         params: DepositParams
@@ -102,11 +449,23 @@ pub mod pocket {
         Ok(())
     }
 
+    pub fn top_up_keeper_tip(
+        ctx: Context<TopUpKeeperTipContext>,
+        params: TopUpKeeperTipParams
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute(params).unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
     pub fn withdraw(
         ctx: Context<WithdrawContext>,
+        params: WithdrawParams
     ) -> Result<()> {
         // process
-        ctx.accounts.execute().unwrap();
+        ctx.accounts.execute(params).unwrap();
 
         // Program result should be ok.
         Ok(())
@@ -122,6 +481,16 @@ pub mod pocket {
         Ok(())
     }
 
+    pub fn teardown_pocket(
+        ctx: Context<TeardownPocketContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
     pub fn init_swap_registry<'info>(
         ctx: Context<'_, '_, '_, 'info, InitAccount<'info>>
     ) -> Result<()> {
@@ -172,4 +541,34 @@ pub mod pocket {
         Ok(())
     }
 
+    pub fn check_start_condition(
+        ctx: Context<CheckStartConditionContext>,
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn migrate_pocket(
+        ctx: Context<MigratePocketContext>
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
+    pub fn migrate_registry(
+        ctx: Context<MigrateRegistryContext>
+    ) -> Result<()> {
+        // process
+        ctx.accounts.execute().unwrap();
+
+        // Program result should be ok.
+        Ok(())
+    }
+
 }
\ No newline at end of file
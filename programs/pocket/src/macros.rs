@@ -7,3 +7,13 @@ macro_rules! token_account_signer {
         &[&[$pre_seed, $seed, $bump][..]]
     };
 }
+
+// Define the seeds to sign with the pocket PDA, bound to the given name
+#[macro_export]
+macro_rules! pocket_signer {
+    ($pocket: expr, $signer: ident) => {
+        let signer_seeds = $pocket.signer_seeds();
+        let seeds = signer_seeds.iter().map(|seed| seed.as_slice()).collect::<Vec<&[u8]>>();
+        let $signer: &[&[&[u8]]] = &[&seeds[..]];
+    };
+}
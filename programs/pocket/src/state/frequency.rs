@@ -0,0 +1,395 @@
+use crate::*;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 3600;
+const SECONDS_PER_DAY: u64 = 86400;
+
+// Here we define how often the pocket runs, from minutes to calendar units. The calendar units run at a fixed UTC time,
+// so that the schedule doesn't drift with the execution delays.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum DateDuration {
+    Minutes {
+        minutes: u64
+    },
+
+    Hours {
+        hours: u64
+    },
+
+    // Every day at the UTC time
+    Daily {
+        hour: u8,
+        minute: u8
+    },
+
+    // Every week on the weekday at the UTC time, the weekday starts from 0 on Monday
+    Weekly {
+        weekday: u8,
+        hour: u8,
+        minute: u8
+    },
+
+    // Every month on the day at the UTC time, the day falls back to the last day of the shorter months
+    Monthly {
+        day: u8,
+        hour: u8,
+        minute: u8
+    },
+}
+
+impl Default for DateDuration {
+    fn default() -> Self {
+        DateDuration::Hours { hours: 0 }
+    }
+}
+
+impl DateDuration {
+    // The serialized size of the largest variant
+    pub const SPACE: usize = 1 + 8;
+
+    // Check whether the frequency is valid
+    pub fn is_valid(&self) -> bool {
+        return match *self {
+            DateDuration::Minutes { minutes } => minutes > 0,
+            DateDuration::Hours { hours } => hours > 0,
+            DateDuration::Daily { hour, minute } => is_valid_time(hour, minute),
+            DateDuration::Weekly { weekday, hour, minute } => weekday < 7 && is_valid_time(hour, minute),
+            DateDuration::Monthly { day, hour, minute } => day >= 1 && day <= 31 && is_valid_time(hour, minute),
+        };
+    }
+
+    // Get the fixed time between two executions, the months have different lengths
+    pub fn interval(&self) -> Option<u64> {
+        return match *self {
+            DateDuration::Minutes { minutes } => Some(minutes.saturating_mul(SECONDS_PER_MINUTE)),
+            DateDuration::Hours { hours } => Some(hours.saturating_mul(SECONDS_PER_HOUR)),
+            DateDuration::Daily { .. } => Some(SECONDS_PER_DAY),
+            DateDuration::Weekly { .. } => Some(7 * SECONDS_PER_DAY),
+            DateDuration::Monthly { .. } => None,
+        };
+    }
+
+    // Compute the next execution time strictly after the start time plus a whole number of intervals,
+    // so that the late executions don't push back the later ones. The calendar units are anchored to the UTC time already.
+    pub fn next_anchored_execution_at(&self, start_at: u64, now: u64) -> u64 {
+        let interval = match *self {
+            DateDuration::Minutes { minutes } => minutes.saturating_mul(SECONDS_PER_MINUTE),
+            DateDuration::Hours { hours } => hours.saturating_mul(SECONDS_PER_HOUR),
+            _ => return self.next_execution_at(now),
+        };
+
+        if now < start_at || interval == 0 {
+            return start_at.max(now);
+        }
+
+        let elapsed_intervals = (now - start_at) / interval + 1;
+
+        return start_at.saturating_add(elapsed_intervals.saturating_mul(interval));
+    }
+
+    // Compute the next execution time strictly after now
+    pub fn next_execution_at(&self, now: u64) -> u64 {
+        return match *self {
+            DateDuration::Minutes { minutes } => now.saturating_add(minutes.saturating_mul(SECONDS_PER_MINUTE)),
+            DateDuration::Hours { hours } => now.saturating_add(hours.saturating_mul(SECONDS_PER_HOUR)),
+
+            DateDuration::Daily { hour, minute } => {
+                let next_at = start_of_day(now) + time_of_day(hour, minute);
+
+                if next_at > now { next_at } else { next_at + SECONDS_PER_DAY }
+            }
+
+            DateDuration::Weekly { weekday, hour, minute } => {
+                let days_ahead = (weekday as u64 + 7 - weekday_of(now)) % 7;
+                let next_at = start_of_day(now) + days_ahead * SECONDS_PER_DAY + time_of_day(hour, minute);
+
+                if next_at > now { next_at } else { next_at + 7 * SECONDS_PER_DAY }
+            }
+
+            DateDuration::Monthly { day, hour, minute } => {
+                let (year, month, _) = civil_from_days(now / SECONDS_PER_DAY);
+                let next_at = monthly_execution_at(year, month, day, hour, minute);
+
+                if next_at > now {
+                    next_at
+                } else if month == 12 {
+                    monthly_execution_at(year + 1, 1, day, hour, minute)
+                } else {
+                    monthly_execution_at(year, month + 1, day, hour, minute)
+                }
+            }
+        };
+    }
+}
+
+// Here we define a UTC time range of the allowed weekdays in which the pocket can be executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct ExecutionWindow {
+    // The allowed weekdays as a bit mask, the bit 0 is Monday
+    pub weekdays: u8,
+
+    // The time range in minutes of the day, the end is excluded
+    pub start_minute: u16,
+    pub end_minute: u16,
+}
+
+impl ExecutionWindow {
+    // The serialized size of a window
+    pub const SPACE: usize = 1 + 2 + 2;
+
+    // Check whether the window is valid, the windows over midnight are split by day
+    pub fn is_valid(&self) -> bool {
+        return self.weekdays > 0
+            && self.weekdays < 1 << 7
+            && self.start_minute < self.end_minute
+            && self.end_minute as u64 <= SECONDS_PER_DAY / SECONDS_PER_MINUTE;
+    }
+
+    // Check whether the timestamp is in the window
+    pub fn contains(&self, timestamp: u64) -> bool {
+        let minute_of_day = (timestamp % SECONDS_PER_DAY) / SECONDS_PER_MINUTE;
+
+        return self.is_open_on(weekday_of(timestamp))
+            && minute_of_day >= self.start_minute as u64
+            && minute_of_day < self.end_minute as u64;
+    }
+
+    // Find the earliest time from the timestamp in the window
+    pub fn next_opening_at(&self, timestamp: u64) -> u64 {
+        for days_ahead in 0..=7 {
+            let day_start = start_of_day(timestamp) + days_ahead * SECONDS_PER_DAY;
+
+            if !self.is_open_on(weekday_of(day_start)) {
+                continue;
+            }
+
+            let open_at = day_start + self.start_minute as u64 * SECONDS_PER_MINUTE;
+            let close_at = day_start + self.end_minute as u64 * SECONDS_PER_MINUTE;
+
+            if timestamp < close_at {
+                return open_at.max(timestamp);
+            }
+        }
+
+        // the window has no weekday
+        return timestamp;
+    }
+
+    fn is_open_on(&self, weekday: u64) -> bool {
+        return self.weekdays & (1 << weekday) != 0;
+    }
+}
+
+fn is_valid_time(hour: u8, minute: u8) -> bool {
+    return hour < 24 && minute < 60;
+}
+
+fn time_of_day(hour: u8, minute: u8) -> u64 {
+    return hour as u64 * SECONDS_PER_HOUR + minute as u64 * SECONDS_PER_MINUTE;
+}
+
+fn start_of_day(timestamp: u64) -> u64 {
+    return timestamp - timestamp % SECONDS_PER_DAY;
+}
+
+// The unix epoch was a Thursday, the weekday starts from 0 on Monday
+fn weekday_of(timestamp: u64) -> u64 {
+    return (timestamp / SECONDS_PER_DAY + 3) % 7;
+}
+
+fn monthly_execution_at(year: u64, month: u64, day: u8, hour: u8, minute: u8) -> u64 {
+    let day = (day as u64).min(days_in_month(year, month));
+
+    return days_from_civil(year, month, day) * SECONDS_PER_DAY + time_of_day(hour, minute);
+}
+
+fn is_leap_year(year: u64) -> bool {
+    return (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+}
+
+fn days_in_month(year: u64, month: u64) -> u64 {
+    return match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+}
+
+// Convert the days since the unix epoch to a (year, month, day) date, see http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return (year, month, day);
+}
+
+// Convert a (year, month, day) date to the days since the unix epoch
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    return era * 146_097 + day_of_era - 719_468;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Monday, 2024-01-01 00:00:00 UTC
+    const MONDAY: u64 = 1_704_067_200;
+
+    #[test]
+    fn should_validate_frequency() {
+        assert!(DateDuration::Minutes { minutes: 15 }.is_valid());
+        assert!(!DateDuration::Minutes { minutes: 0 }.is_valid());
+        assert!(DateDuration::Hours { hours: 1 }.is_valid());
+        assert!(!DateDuration::Hours { hours: 0 }.is_valid());
+
+        assert!(DateDuration::Daily { hour: 23, minute: 59 }.is_valid());
+        assert!(!DateDuration::Daily { hour: 24, minute: 0 }.is_valid());
+        assert!(!DateDuration::Daily { hour: 0, minute: 60 }.is_valid());
+
+        assert!(DateDuration::Weekly { weekday: 6, hour: 9, minute: 0 }.is_valid());
+        assert!(!DateDuration::Weekly { weekday: 7, hour: 9, minute: 0 }.is_valid());
+
+        assert!(DateDuration::Monthly { day: 1, hour: 0, minute: 0 }.is_valid());
+        assert!(DateDuration::Monthly { day: 31, hour: 0, minute: 0 }.is_valid());
+        assert!(!DateDuration::Monthly { day: 0, hour: 0, minute: 0 }.is_valid());
+        assert!(!DateDuration::Monthly { day: 32, hour: 0, minute: 0 }.is_valid());
+    }
+
+    #[test]
+    fn should_compute_next_execution_of_relative_units() {
+        let now = MONDAY + 123;
+
+        assert_eq!(DateDuration::Minutes { minutes: 15 }.next_execution_at(now), now + 15 * 60);
+        assert_eq!(DateDuration::Hours { hours: 2 }.next_execution_at(now), now + 2 * 3600);
+        assert_eq!(DateDuration::Hours { hours: u64::MAX }.next_execution_at(now), u64::MAX);
+    }
+
+    #[test]
+    fn should_compute_next_anchored_execution() {
+        let start_at = MONDAY + 9 * 3600;
+        let frequency = DateDuration::Hours { hours: 24 };
+
+        // the late executions keep the schedule at 09:00
+        assert_eq!(frequency.next_anchored_execution_at(start_at, start_at), start_at + 86400);
+        assert_eq!(frequency.next_anchored_execution_at(start_at, start_at + 3600), start_at + 86400);
+        assert_eq!(frequency.next_anchored_execution_at(start_at, start_at + 86400 + 5 * 3600), start_at + 2 * 86400);
+
+        // the missed executions are not replayed
+        assert_eq!(frequency.next_anchored_execution_at(start_at, start_at + 3 * 86400 + 1), start_at + 4 * 86400);
+
+        let frequency = DateDuration::Minutes { minutes: 15 };
+        assert_eq!(frequency.next_anchored_execution_at(start_at, start_at + 20 * 60), start_at + 30 * 60);
+        assert_eq!(frequency.next_anchored_execution_at(start_at, start_at - 60), start_at);
+
+        // the calendar units are not affected by the start time
+        let frequency = DateDuration::Daily { hour: 9, minute: 0 };
+        assert_eq!(frequency.next_anchored_execution_at(MONDAY + 60, start_at), frequency.next_execution_at(start_at));
+    }
+
+    #[test]
+    fn should_compute_next_daily_execution() {
+        let frequency = DateDuration::Daily { hour: 9, minute: 30 };
+        let today_at = MONDAY + 9 * 3600 + 30 * 60;
+
+        assert_eq!(frequency.next_execution_at(MONDAY), today_at);
+        assert_eq!(frequency.next_execution_at(today_at - 1), today_at);
+
+        // the execution time of today has passed
+        assert_eq!(frequency.next_execution_at(today_at), today_at + 86400);
+        assert_eq!(frequency.next_execution_at(today_at + 60), today_at + 86400);
+    }
+
+    #[test]
+    fn should_compute_next_weekly_execution() {
+        assert_eq!(weekday_of(MONDAY), 0);
+
+        // every Monday at 09:00 UTC
+        let frequency = DateDuration::Weekly { weekday: 0, hour: 9, minute: 0 };
+        let monday_at = MONDAY + 9 * 3600;
+
+        assert_eq!(frequency.next_execution_at(MONDAY), monday_at);
+        assert_eq!(frequency.next_execution_at(monday_at), monday_at + 7 * 86400);
+        assert_eq!(frequency.next_execution_at(MONDAY + 3 * 86400), monday_at + 7 * 86400);
+
+        // every Sunday at 18:15 UTC
+        let frequency = DateDuration::Weekly { weekday: 6, hour: 18, minute: 15 };
+        let sunday_at = MONDAY + 6 * 86400 + 18 * 3600 + 15 * 60;
+
+        assert_eq!(frequency.next_execution_at(MONDAY), sunday_at);
+        assert_eq!(frequency.next_execution_at(sunday_at + 1), sunday_at + 7 * 86400);
+    }
+
+    #[test]
+    fn should_compute_next_monthly_execution() {
+        // 2024-01-01, 2024-01-31, 2024-02-29, 2024-03-31, 2024-12-15 and 2025-01-15
+        let jan_1 = MONDAY;
+        let jan_31 = jan_1 + 30 * 86400;
+        let feb_29 = jan_31 + 29 * 86400;
+        let mar_31 = feb_29 + 31 * 86400;
+        let dec_15 = 1_734_220_800;
+        let jan_15 = dec_15 + 31 * 86400;
+
+        assert_eq!(civil_from_days(feb_29 / 86400), (2024, 2, 29));
+        assert_eq!(civil_from_days(dec_15 / 86400), (2024, 12, 15));
+        assert_eq!(days_from_civil(2025, 1, 15) * 86400, jan_15);
+
+        let frequency = DateDuration::Monthly { day: 1, hour: 0, minute: 0 };
+        assert_eq!(frequency.next_execution_at(jan_1 - 1), jan_1);
+        assert_eq!(frequency.next_execution_at(jan_1), jan_31 + 86400);
+
+        // the last day of the month is used when the month is shorter
+        let frequency = DateDuration::Monthly { day: 31, hour: 12, minute: 0 };
+        assert_eq!(frequency.next_execution_at(jan_1), jan_31 + 12 * 3600);
+        assert_eq!(frequency.next_execution_at(jan_31 + 12 * 3600), feb_29 + 12 * 3600);
+        assert_eq!(frequency.next_execution_at(feb_29 + 13 * 3600), mar_31 + 12 * 3600);
+
+        // the schedule rolls over to the next year
+        let frequency = DateDuration::Monthly { day: 15, hour: 8, minute: 45 };
+        assert_eq!(frequency.next_execution_at(dec_15 + 9 * 3600), jan_15 + 8 * 3600 + 45 * 60);
+    }
+
+    #[test]
+    fn should_find_next_opening_of_execution_window() {
+        // weekdays from 14:00 to 16:30 UTC
+        let window = ExecutionWindow { weekdays: 0b0011111, start_minute: 14 * 60, end_minute: 16 * 60 + 30 };
+        let friday = MONDAY + 4 * 86400;
+
+        assert!(window.is_valid());
+        assert!(!ExecutionWindow { weekdays: 0, ..window }.is_valid());
+        assert!(!ExecutionWindow { weekdays: 1 << 7, ..window }.is_valid());
+        assert!(!ExecutionWindow { start_minute: 60, end_minute: 60, ..window }.is_valid());
+        assert!(!ExecutionWindow { end_minute: 24 * 60 + 1, ..window }.is_valid());
+
+        assert!(window.contains(MONDAY + 14 * 3600));
+        assert!(window.contains(friday + 16 * 3600 + 29 * 60));
+        assert!(!window.contains(friday + 16 * 3600 + 30 * 60));
+        assert!(!window.contains(friday + 2 * 86400 + 15 * 3600));
+
+        // the timestamp in the window is kept
+        assert_eq!(window.next_opening_at(MONDAY + 15 * 3600), MONDAY + 15 * 3600);
+
+        // rolled forward to the opening of the day, or of the next allowed day
+        assert_eq!(window.next_opening_at(MONDAY + 9 * 3600), MONDAY + 14 * 3600);
+        assert_eq!(window.next_opening_at(MONDAY + 17 * 3600), MONDAY + 86400 + 14 * 3600);
+        assert_eq!(window.next_opening_at(friday + 17 * 3600), MONDAY + 7 * 86400 + 14 * 3600);
+
+        // only on Mondays, the window of the next week is used once it's closed
+        let window = ExecutionWindow { weekdays: 1, start_minute: 0, end_minute: 60 };
+        assert_eq!(window.next_opening_at(MONDAY + 3600), MONDAY + 7 * 86400);
+    }
+}
@@ -1,7 +1,13 @@
 pub mod pocket_pool;
 pub mod lookup_table_registry;
 pub mod pocket_platform_registry;
+pub mod multisig_proposal;
+pub mod pocket_owner_index;
+pub mod frequency;
 
 pub use pocket_pool::*;
 pub use lookup_table_registry::*;
 pub use pocket_platform_registry::*;
+pub use multisig_proposal::*;
+pub use pocket_owner_index::*;
+pub use frequency::*;
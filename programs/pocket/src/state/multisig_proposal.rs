@@ -0,0 +1,49 @@
+use crate::*;
+
+// Here we define the account state that holds an administrative action waiting for the multisig approvals.
+#[account]
+pub struct MultisigProposal {
+    // Define the id of the proposal
+    pub id: u64,
+
+    // Bump to help define the PDA of the proposal
+    pub bump: u8,
+
+    // Define the signer who proposed the action, the rent is refunded to the proposer once the proposal is closed
+    pub proposer: Pubkey,
+
+    // Define the proposed action
+    pub action: AdminAction,
+
+    // Define the signers who approved the action
+    pub approvals: Vec<Pubkey>,
+
+    // Define the proposed time
+    pub created_at: u64,
+}
+
+// Define handler
+impl MultisigProposal {
+    // Compute the account space, the approvals are reserved for every multisig signer
+    pub fn space(action: &AdminAction) -> usize {
+        return 8 // discriminator
+            + 8 // id
+            + 1 // bump
+            + 32 // proposer
+            + action.try_to_vec().unwrap().len() // action
+            + 4 + 32 * MAX_MULTISIG_SIGNERS // approvals
+            + 8; // created at
+    }
+
+    // Record the approval of a signer
+    pub fn approve(&mut self, signer: Pubkey) -> Result<()> {
+        if self.approvals.contains(&signer) {
+            msg!("ERROR::PROPOSAL::ALREADY_APPROVED");
+            return Err(PocketError::ProposalAlreadyApproved.into());
+        }
+
+        self.approvals.push(signer);
+
+        Ok(())
+    }
+}
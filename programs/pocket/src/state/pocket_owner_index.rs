@@ -0,0 +1,136 @@
+use crate::*;
+
+// Here we define a pocket listed in the owner index.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct IndexedPocket {
+    pub pocket_address: Pubkey,
+    pub status: PocketStatus,
+}
+
+impl IndexedPocket {
+    // The serialized size of an indexed pocket
+    pub const SPACE: usize = 32 + 1;
+}
+
+// Here we define the account state that lists the pockets of an owner. The index will be the PDA of the owner.
+#[account]
+#[derive(Default)]
+pub struct PocketOwnerIndex {
+    // Bump to help define the PDA of the index
+    pub bump: u8,
+
+    // Define the owner of the listed pockets
+    pub owner: Pubkey,
+
+    // Define the nonce of the next pocket created with a deterministic address
+    pub next_nonce: u64,
+
+    // Define the listed pockets, the statuses are refreshed when the pocket is synced
+    pub pockets: Vec<IndexedPocket>,
+}
+
+// Define handler
+impl PocketOwnerIndex {
+    // Compute the account space
+    pub fn space(pockets_length: usize) -> usize {
+        return 8 // discriminator
+            + 1 // bump
+            + 32 // owner
+            + 8 // next nonce
+            + 4 + pockets_length * IndexedPocket::SPACE; // pockets
+    }
+
+    // Fill in the state of a newly created index
+    pub fn initialize(&mut self, owner: Pubkey, bump: u8) {
+        if self.owner == Pubkey::default() {
+            self.owner = owner;
+            self.bump = bump;
+        }
+    }
+
+    // Take the nonce of the next deterministic pocket
+    pub fn take_nonce(&mut self) -> u64 {
+        let nonce = self.next_nonce;
+        self.next_nonce += 1;
+
+        return nonce;
+    }
+
+    // Add the pocket to the index, or refresh its status. Returns false when the index is full.
+    pub fn upsert_pocket(&mut self, pocket_address: Pubkey, status: PocketStatus) -> bool {
+        match self.pockets.iter_mut().find(|x| x.pocket_address == pocket_address) {
+            Some(indexed_pocket) => indexed_pocket.status = status,
+            None => {
+                if self.pockets.len() >= MAX_INDEXED_POCKETS {
+                    return false;
+                }
+
+                self.pockets.push(IndexedPocket { pocket_address, status });
+            }
+        }
+
+        return true;
+    }
+
+    // Refresh the status of a listed pocket, the pockets which were never indexed are ignored
+    pub fn set_pocket_status(&mut self, pocket_address: Pubkey, status: PocketStatus) {
+        match self.pockets.iter_mut().find(|x| x.pocket_address == pocket_address) {
+            Some(indexed_pocket) => indexed_pocket.status = status,
+            None => {}
+        }
+    }
+
+    // Remove the pocket from the index, the pockets which were never indexed are ignored
+    pub fn remove_pocket(&mut self, pocket_address: Pubkey) {
+        self.pockets.retain(|x| x.pocket_address != pocket_address);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_upsert_and_remove_pockets() {
+        let mut index = PocketOwnerIndex::default();
+        let pocket_address = Pubkey::new_unique();
+
+        assert!(index.upsert_pocket(pocket_address, PocketStatus::Active));
+        assert!(index.upsert_pocket(pocket_address, PocketStatus::Closed));
+        assert_eq!(index.pockets, vec![IndexedPocket { pocket_address, status: PocketStatus::Closed }]);
+
+        index.set_pocket_status(pocket_address, PocketStatus::Paused);
+        index.set_pocket_status(Pubkey::new_unique(), PocketStatus::Paused);
+        assert_eq!(index.pockets, vec![IndexedPocket { pocket_address, status: PocketStatus::Paused }]);
+
+        index.remove_pocket(Pubkey::new_unique());
+        assert_eq!(index.pockets.len(), 1);
+
+        index.remove_pocket(pocket_address);
+        assert!(index.pockets.is_empty());
+    }
+
+    #[test]
+    fn should_limit_indexed_pockets() {
+        let mut index = PocketOwnerIndex {
+            pockets: vec![IndexedPocket::default(); MAX_INDEXED_POCKETS],
+            ..PocketOwnerIndex::default()
+        };
+
+        assert!(!index.upsert_pocket(Pubkey::new_unique(), PocketStatus::Active));
+        assert_eq!(index.pockets.len(), MAX_INDEXED_POCKETS);
+
+        let mut data: Vec<u8> = Vec::new();
+        index.try_serialize(&mut data).unwrap();
+        assert_eq!(PocketOwnerIndex::space(MAX_INDEXED_POCKETS), data.len());
+    }
+
+    #[test]
+    fn should_take_nonces_in_order() {
+        let mut index = PocketOwnerIndex::default();
+
+        assert_eq!(index.take_nonce(), 0);
+        assert_eq!(index.take_nonce(), 1);
+        assert_eq!(index.next_nonce, 2);
+    }
+}
@@ -11,6 +11,177 @@ pub struct MintInfo {
     pub bump: u8,
 }
 
+// Here we define the approved market, keyed by its token pair and dex program.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct MarketInfo {
+    // Whether the market is active or not.
+    pub is_enabled: bool,
+    pub market_key: Pubkey,
+    pub base_mint_address: Pubkey,
+    pub quote_mint_address: Pubkey,
+    pub dex_program: Pubkey,
+}
+
+// Here we define the roles which can be granted on the platform.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum PlatformRole {
+    // Manage roles and the platform config
+    Admin,
+
+    // Execute swaps on pockets
+    Operator,
+
+    // Pause and unpause the platform
+    Pauser,
+
+    // Manage the platform fee and claim the treasury
+    FeeManager,
+
+    // Manage the whitelisted mints and markets
+    MintCurator,
+}
+
+// Here we define the administrative changes which must go through the timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum AdminAction {
+    // Replace the operator set
+    UpdateOperators {
+        operators: Vec<Pubkey>,
+    },
+
+    // Update the platform fee and the default keeper tip
+    UpdatePlatformFee {
+        platform_fee_bps: u64,
+        keeper_tip_amount: u64,
+    },
+
+    // Update the timelock delay itself
+    UpdateTimelockDelay {
+        timelock_delay: u64,
+    },
+
+    // Add a mint account into the whitelist
+    AddMintAccount {
+        mint_account: Pubkey,
+    },
+
+    // Enable or disable a whitelisted mint account
+    SetMintAccountEnabled {
+        mint_account: Pubkey,
+        is_enabled: bool,
+    },
+
+    // Remove a mint account from the whitelist
+    RemoveMintAccount {
+        mint_account: Pubkey,
+    },
+
+    // Pause or unpause the platform
+    SetPaused {
+        is_paused: bool,
+    },
+
+    // Update the multisig signers and threshold, a zero threshold disables the multisig
+    UpdateMultisig {
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+
+    // Grant the role to an account
+    GrantRole {
+        role: PlatformRole,
+        account: Pubkey,
+    },
+
+    // Revoke the role from an account
+    RevokeRole {
+        role: PlatformRole,
+        account: Pubkey,
+    },
+
+    // Propose the new registry owner, the new owner still has to accept the ownership
+    ProposeRegistryOwner {
+        new_owner: Pubkey,
+    },
+
+    // Add a market into the whitelist, the token pair is verified against the market state when a pocket is created
+    AddMarket {
+        market_key: Pubkey,
+        base_mint_address: Pubkey,
+        quote_mint_address: Pubkey,
+        dex_program: Pubkey,
+    },
+
+    // Enable or disable a whitelisted market
+    SetMarketEnabled {
+        market_key: Pubkey,
+        is_enabled: bool,
+    },
+
+    // Remove a market from the whitelist
+    RemoveMarket {
+        market_key: Pubkey,
+    },
+}
+
+impl AdminAction {
+    // The role which is allowed to queue, execute and cancel the action
+    pub fn required_role(&self) -> PlatformRole {
+        return match self {
+            AdminAction::UpdatePlatformFee { .. } => PlatformRole::FeeManager,
+            AdminAction::AddMintAccount { .. }
+            | AdminAction::SetMintAccountEnabled { .. }
+            | AdminAction::RemoveMintAccount { .. }
+            | AdminAction::AddMarket { .. }
+            | AdminAction::SetMarketEnabled { .. }
+            | AdminAction::RemoveMarket { .. } => PlatformRole::MintCurator,
+            AdminAction::SetPaused { .. } => PlatformRole::Pauser,
+            _ => PlatformRole::Admin,
+        };
+    }
+
+    // Whether the action must wait for the timelock delay before it is applied
+    pub fn is_timelocked(&self) -> bool {
+        return match self {
+            AdminAction::UpdateOperators { .. }
+            | AdminAction::UpdatePlatformFee { .. }
+            | AdminAction::UpdateTimelockDelay { .. }
+            | AdminAction::UpdateMultisig { .. }
+            | AdminAction::GrantRole { .. }
+            | AdminAction::RevokeRole { .. } => true,
+            _ => false,
+        };
+    }
+
+    // Whether the action can only be queued by the owner, as managing the admins, the multisig or the ownership
+    pub fn is_owner_only(&self) -> bool {
+        return match self {
+            AdminAction::GrantRole { role, .. } | AdminAction::RevokeRole { role, .. } => *role == PlatformRole::Admin,
+            AdminAction::UpdateMultisig { .. } | AdminAction::ProposeRegistryOwner { .. } => true,
+            _ => false,
+        };
+    }
+
+    // Whether the action must be approved by the multisig once it is enabled
+    pub fn is_multisig_required(&self) -> bool {
+        return match self {
+            AdminAction::UpdatePlatformFee { .. } => false,
+            _ => true,
+        };
+    }
+}
+
+// Here we define an administrative change waiting for the timelock to expire.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct QueuedChange {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+
+    // The change is only executable from this timestamp
+    pub eta: u64,
+}
+
 #[account]
 #[derive(Default)]
 pub struct PocketPlatformRegistry {
@@ -28,6 +199,46 @@ pub struct PocketPlatformRegistry {
 
     // define whitelisted mint token account
     pub operators: Vec<Pubkey>,
+
+    // define whitelisted markets
+    pub allowed_markets: Vec<MarketInfo>,
+
+    // define the platform fee charged on every executed swap, in basis points
+    pub platform_fee_bps: u64,
+
+    // define the default tip in lamports paid to the operator for every executed batch
+    pub keeper_tip_amount: u64,
+
+    // define the proposed owner, who must accept the ownership before it is transferred
+    pub pending_owner: Option<Pubkey>,
+
+    // define the granted roles, the owner implicitly holds every role except operator
+    pub admins: Vec<Pubkey>,
+    pub pausers: Vec<Pubkey>,
+    pub fee_managers: Vec<Pubkey>,
+    pub mint_curators: Vec<Pubkey>,
+
+    // define whether the platform is paused, only withdrawals are allowed while paused
+    pub is_paused: bool,
+
+    // define the delay in seconds before a queued administrative change can be executed
+    pub timelock_delay: u64,
+
+    // define the id of the next queued change
+    pub next_change_id: u64,
+
+    // define the administrative changes waiting for the timelock
+    pub queued_changes: Vec<QueuedChange>,
+
+    // define the multisig signers and the approvals required, a zero threshold means the multisig is disabled
+    pub multisig_signers: Vec<Pubkey>,
+    pub multisig_threshold: u8,
+
+    // define the id of the next multisig proposal
+    pub next_proposal_id: u64,
+
+    // define the layout version, the v1 registry created before versioning reads as zero
+    pub version: u8,
 }
 
 // Define handler
@@ -43,6 +254,28 @@ impl PocketPlatformRegistry {
         return Err(PocketError::AlreadyInitialized.into());
     }
 
+    // Compute the space of a newly initialized registry, the registry is reallocated when it grows
+    pub fn initial_space(operators_length: usize) -> usize {
+        let pocket_registry = PocketPlatformRegistry {
+            operators: vec![Pubkey::default(); operators_length],
+            ..PocketPlatformRegistry::default()
+        };
+
+        return 8 + pocket_registry.try_to_vec().unwrap().len();
+    }
+
+    // Upgrade the legacy layout in place, the appended fields are zero-initialized by the realloc
+    pub fn migrate(&mut self) -> Result<()> {
+        if self.version >= REGISTRY_VERSION {
+            msg!("ERROR::PLATFORM::ALREADY_MIGRATED");
+            return Err(PocketError::AlreadyMigrated.into());
+        }
+
+        self.version = REGISTRY_VERSION;
+
+        Ok(())
+    }
+
     // Check whether the mint account was previously added or not.
     pub fn is_mint_account_existed(&self, mint_account: Pubkey) -> bool {
         return self.allowed_mint_accounts.iter()
@@ -68,10 +301,574 @@ impl PocketPlatformRegistry {
             .unwrap();
     }
 
+    // Add a new mint account into the whitelist, the mint account is enabled by default.
+    pub fn add_mint_account(&mut self, mint_account: Pubkey) -> Result<()> {
+        if self.is_mint_account_existed(mint_account) {
+            msg!("ERROR::PLATFORM::MINT_ACCOUNT_EXISTED");
+            return Err(PocketError::MintAccountExisted.into());
+        }
+
+        if self.allowed_mint_accounts.len() >= MAX_ALLOWED_MINT_ACCOUNTS {
+            msg!("ERROR::PLATFORM::TOO_MANY_MINT_ACCOUNTS");
+            return Err(PocketError::TooManyMintAccounts.into());
+        }
+
+        self.allowed_mint_accounts.push(MintInfo {
+            is_enabled: true,
+            mint_account,
+            ..MintInfo::default()
+        });
+
+        Ok(())
+    }
+
+    // Enable or disable a whitelisted mint account
+    pub fn set_mint_account_enabled(&mut self, mint_account: Pubkey, is_enabled: bool) -> Result<()> {
+        return match self.allowed_mint_accounts.iter_mut()
+            .find(|mint_info| mint_info.mint_account == mint_account) {
+            Some(mint_info) => {
+                mint_info.is_enabled = is_enabled;
+                Ok(())
+            }
+
+            None => {
+                msg!("ERROR::PLATFORM::MINT_ACCOUNT_NOT_EXISTED");
+                Err(PocketError::MintAccountNotExisted.into())
+            }
+        };
+    }
+
+    // Remove a mint account from the whitelist
+    pub fn remove_mint_account(&mut self, mint_account: Pubkey) -> Result<()> {
+        if !self.is_mint_account_existed(mint_account) {
+            msg!("ERROR::PLATFORM::MINT_ACCOUNT_NOT_EXISTED");
+            return Err(PocketError::MintAccountNotExisted.into());
+        }
+
+        self.allowed_mint_accounts.retain(|mint_info| mint_info.mint_account != mint_account);
+
+        Ok(())
+    }
+
+    // Assign the treasury vault of a whitelisted mint account
+    pub fn set_treasury_vault(&mut self, mint_account: Pubkey, token_account: Pubkey, bump: u8) -> Result<()> {
+        return match self.allowed_mint_accounts.iter_mut()
+            .find(|mint_info| mint_info.mint_account == mint_account) {
+            Some(mint_info) => {
+                mint_info.token_account = token_account;
+                mint_info.bump = bump;
+                Ok(())
+            }
+
+            None => {
+                msg!("ERROR::PLATFORM::MINT_ACCOUNT_NOT_EXISTED");
+                Err(PocketError::MintAccountNotExisted.into())
+            }
+        };
+    }
+
+    // Update the platform fee
+    pub fn set_platform_fee(&mut self, platform_fee_bps: u64) -> Result<()> {
+        if platform_fee_bps > MAX_PLATFORM_FEE_BPS {
+            msg!("ERROR::PLATFORM::INVALID_FEE_RATE");
+            return Err(PocketError::InvalidFeeRate.into());
+        }
+
+        self.platform_fee_bps = platform_fee_bps;
+
+        Ok(())
+    }
+
+    // Find the treasury vault which collects the platform fee in the mint
+    pub fn find_treasury_vault_address(mint_address: Pubkey) -> Pubkey {
+        return Pubkey::find_program_address(
+            &[TREASURY_SEED, mint_address.as_ref()],
+            &crate::ID,
+        ).0;
+    }
+
+    // Compute the platform fee charged on the swapped amount
+    pub fn compute_platform_fee(&self, amount: u64) -> u64 {
+        return (amount as u128)
+            .checked_mul(self.platform_fee_bps as u128)
+            .unwrap()
+            .checked_div(PLATFORM_FEE_DENOMINATOR as u128)
+            .unwrap() as u64;
+    }
+
+    // Check whether the market was previously added or not.
+    pub fn is_market_existed(&self, market_key: Pubkey) -> bool {
+        return self.get_market_info(market_key).is_some();
+    }
+
+    // Get market info
+    pub fn get_market_info(&self, market_key: Pubkey) -> Option<&MarketInfo> {
+        return self.allowed_markets.iter()
+            .find(|&market_info| market_info.market_key == market_key);
+    }
+
+    // Add a new market into the whitelist, the market is enabled by default.
+    pub fn add_market(&mut self, market_info: MarketInfo) -> Result<()> {
+        if self.is_market_existed(market_info.market_key) {
+            msg!("ERROR::PLATFORM::MARKET_EXISTED");
+            return Err(PocketError::MarketExisted.into());
+        }
+
+        if self.allowed_markets.len() >= MAX_ALLOWED_MARKETS {
+            msg!("ERROR::PLATFORM::TOO_MANY_MARKETS");
+            return Err(PocketError::TooManyMarkets.into());
+        }
+
+        self.allowed_markets.push(MarketInfo {
+            is_enabled: true,
+            ..market_info
+        });
+
+        Ok(())
+    }
+
+    // Enable or disable a whitelisted market
+    pub fn set_market_enabled(&mut self, market_key: Pubkey, is_enabled: bool) -> Result<()> {
+        return match self.allowed_markets.iter_mut()
+            .find(|market_info| market_info.market_key == market_key) {
+            Some(market_info) => {
+                market_info.is_enabled = is_enabled;
+                Ok(())
+            }
+
+            None => {
+                msg!("ERROR::PLATFORM::MARKET_NOT_EXISTED");
+                Err(PocketError::MarketNotExisted.into())
+            }
+        };
+    }
+
+    // Remove a market from the whitelist
+    pub fn remove_market(&mut self, market_key: Pubkey) -> Result<()> {
+        if !self.is_market_existed(market_key) {
+            msg!("ERROR::PLATFORM::MARKET_NOT_EXISTED");
+            return Err(PocketError::MarketNotExisted.into());
+        }
+
+        self.allowed_markets.retain(|market_info| market_info.market_key != market_key);
+
+        Ok(())
+    }
+
     // Detect if a pubkey was belong to an operator
     pub fn is_operator(&self, operator_pubkey: Pubkey) -> bool {
         return self.operators.iter()
             .find(|&pubkey| pubkey.clone() == operator_pubkey.clone())
             .is_some();
     }
-}
\ No newline at end of file
+
+    // Detect if a pubkey was belong to an admin
+    pub fn is_admin(&self, pubkey: Pubkey) -> bool {
+        return self.has_role(PlatformRole::Admin, pubkey);
+    }
+
+    // Detect if a pubkey was belong to a pauser
+    pub fn is_pauser(&self, pubkey: Pubkey) -> bool {
+        return self.has_role(PlatformRole::Pauser, pubkey);
+    }
+
+    // Detect if a pubkey was belong to a fee manager
+    pub fn is_fee_manager(&self, pubkey: Pubkey) -> bool {
+        return self.has_role(PlatformRole::FeeManager, pubkey);
+    }
+
+    // Detect if a pubkey was belong to a mint curator
+    pub fn is_mint_curator(&self, pubkey: Pubkey) -> bool {
+        return self.has_role(PlatformRole::MintCurator, pubkey);
+    }
+
+    // Check whether the pubkey holds the role
+    pub fn has_role(&self, role: PlatformRole, pubkey: Pubkey) -> bool {
+        if role != PlatformRole::Operator && self.owner == pubkey {
+            return true;
+        }
+
+        return self.get_role_members(role).contains(&pubkey);
+    }
+
+    // Grant the role to a pubkey
+    pub fn grant_role(&mut self, role: PlatformRole, pubkey: Pubkey) -> Result<()> {
+        if self.get_role_members(role).contains(&pubkey) {
+            msg!("ERROR::PLATFORM::ROLE_EXISTED");
+            return Err(PocketError::RoleExisted.into());
+        }
+
+        if role == PlatformRole::Operator && self.operators.len() >= MAX_OPERATORS {
+            msg!("ERROR::PLATFORM::TOO_MANY_OPERATORS");
+            return Err(PocketError::TooManyOperators.into());
+        }
+
+        if self.get_role_members(role).len() >= MAX_ROLE_MEMBERS {
+            msg!("ERROR::PLATFORM::TOO_MANY_ROLE_MEMBERS");
+            return Err(PocketError::TooManyRoleMembers.into());
+        }
+
+        self.get_role_members_mut(role).push(pubkey);
+
+        Ok(())
+    }
+
+    // Revoke the role from a pubkey
+    pub fn revoke_role(&mut self, role: PlatformRole, pubkey: Pubkey) -> Result<()> {
+        if !self.get_role_members(role).contains(&pubkey) {
+            msg!("ERROR::PLATFORM::ROLE_NOT_EXISTED");
+            return Err(PocketError::RoleNotExisted.into());
+        }
+
+        self.get_role_members_mut(role).retain(|&member| member != pubkey);
+
+        Ok(())
+    }
+
+    // Whether the administrative changes must go through the timelock
+    pub fn is_timelock_enabled(&self) -> bool {
+        return self.timelock_delay > 0;
+    }
+
+    // Queue an administrative change, which is executable once the timelock delay passed
+    pub fn queue_change(&mut self, proposer: Pubkey, action: AdminAction, now: u64) -> Result<QueuedChange> {
+        self.validate_admin_action(&action).unwrap();
+
+        if self.queued_changes.len() >= MAX_QUEUED_CHANGES {
+            msg!("ERROR::PLATFORM::TOO_MANY_QUEUED_CHANGES");
+            return Err(PocketError::TooManyQueuedChanges.into());
+        }
+
+        let queued_change = QueuedChange {
+            id: self.next_change_id,
+            proposer,
+            action,
+            eta: now.checked_add(self.timelock_delay).unwrap(),
+        };
+
+        self.next_change_id = self.next_change_id.checked_add(1).unwrap();
+        self.queued_changes.push(queued_change.clone());
+
+        Ok(queued_change)
+    }
+
+    // Get queued change
+    pub fn get_queued_change(&self, change_id: u64) -> Option<&QueuedChange> {
+        return self.queued_changes.iter()
+            .find(|&queued_change| queued_change.id == change_id);
+    }
+
+    // Remove the queued change once its timelock expired, and apply it
+    pub fn execute_change(&mut self, change_id: u64, now: u64) -> Result<QueuedChange> {
+        let queued_change = match self.get_queued_change(change_id) {
+            Some(queued_change) => queued_change.clone(),
+            None => {
+                msg!("ERROR::PLATFORM::QUEUED_CHANGE_NOT_EXISTED");
+                return Err(PocketError::QueuedChangeNotExisted.into());
+            }
+        };
+
+        if queued_change.eta > now {
+            msg!("ERROR::PLATFORM::TIMELOCK_NOT_EXPIRED");
+            return Err(PocketError::TimelockNotExpired.into());
+        }
+
+        self.queued_changes.retain(|change| change.id != change_id);
+        self.apply_admin_action(&queued_change.action).unwrap();
+
+        Ok(queued_change)
+    }
+
+    // Remove the queued change before it is executed
+    pub fn cancel_change(&mut self, change_id: u64) -> Result<QueuedChange> {
+        let queued_change = match self.get_queued_change(change_id) {
+            Some(queued_change) => queued_change.clone(),
+            None => {
+                msg!("ERROR::PLATFORM::QUEUED_CHANGE_NOT_EXISTED");
+                return Err(PocketError::QueuedChangeNotExisted.into());
+            }
+        };
+
+        self.queued_changes.retain(|change| change.id != change_id);
+
+        Ok(queued_change)
+    }
+
+    // Apply the administrative change to the registry
+    pub fn apply_admin_action(&mut self, action: &AdminAction) -> Result<()> {
+        self.validate_admin_action(action).unwrap();
+
+        match action.clone() {
+            AdminAction::UpdateOperators { operators } => {
+                self.operators = operators;
+            }
+
+            AdminAction::UpdatePlatformFee { platform_fee_bps, keeper_tip_amount } => {
+                self.set_platform_fee(platform_fee_bps).unwrap();
+                self.keeper_tip_amount = keeper_tip_amount;
+            }
+
+            AdminAction::UpdateTimelockDelay { timelock_delay } => {
+                self.timelock_delay = timelock_delay;
+            }
+
+            AdminAction::AddMintAccount { mint_account } => {
+                self.add_mint_account(mint_account).unwrap();
+            }
+
+            AdminAction::SetMintAccountEnabled { mint_account, is_enabled } => {
+                self.set_mint_account_enabled(mint_account, is_enabled).unwrap();
+            }
+
+            AdminAction::RemoveMintAccount { mint_account } => {
+                self.remove_mint_account(mint_account).unwrap();
+            }
+
+            AdminAction::SetPaused { is_paused } => {
+                self.is_paused = is_paused;
+            }
+
+            AdminAction::UpdateMultisig { signers, threshold } => {
+                self.multisig_signers = signers;
+                self.multisig_threshold = threshold;
+            }
+
+            AdminAction::GrantRole { role, account } => {
+                self.grant_role(role, account).unwrap();
+            }
+
+            AdminAction::RevokeRole { role, account } => {
+                self.revoke_role(role, account).unwrap();
+            }
+
+            AdminAction::ProposeRegistryOwner { new_owner } => {
+                self.pending_owner = Some(new_owner);
+            }
+
+            AdminAction::AddMarket { market_key, base_mint_address, quote_mint_address, dex_program } => {
+                self.add_market(MarketInfo {
+                    is_enabled: true,
+                    market_key,
+                    base_mint_address,
+                    quote_mint_address,
+                    dex_program,
+                }).unwrap();
+            }
+
+            AdminAction::SetMarketEnabled { market_key, is_enabled } => {
+                self.set_market_enabled(market_key, is_enabled).unwrap();
+            }
+
+            AdminAction::RemoveMarket { market_key } => {
+                self.remove_market(market_key).unwrap();
+            }
+        }
+
+        Ok(())
+    }
+
+    // Validate the administrative change before it is queued or applied
+    fn validate_admin_action(&self, action: &AdminAction) -> Result<()> {
+        match action {
+            AdminAction::UpdateOperators { operators } => {
+                if operators.len() > MAX_OPERATORS {
+                    msg!("ERROR::PLATFORM::TOO_MANY_OPERATORS");
+                    return Err(PocketError::TooManyOperators.into());
+                }
+            }
+
+            AdminAction::UpdatePlatformFee { platform_fee_bps, .. } => {
+                if *platform_fee_bps > MAX_PLATFORM_FEE_BPS {
+                    msg!("ERROR::PLATFORM::INVALID_FEE_RATE");
+                    return Err(PocketError::InvalidFeeRate.into());
+                }
+            }
+
+            AdminAction::UpdateTimelockDelay { timelock_delay } => {
+                if *timelock_delay > MAX_TIMELOCK_DELAY {
+                    msg!("ERROR::PLATFORM::INVALID_TIMELOCK_DELAY");
+                    return Err(PocketError::InvalidTimelockDelay.into());
+                }
+            }
+
+            AdminAction::UpdateMultisig { signers, threshold } => {
+                let mut unique_signers = signers.clone();
+                unique_signers.sort();
+                unique_signers.dedup();
+
+                if signers.len() > MAX_MULTISIG_SIGNERS
+                    || unique_signers.len() != signers.len()
+                    || *threshold as usize > signers.len() {
+                    msg!("ERROR::PLATFORM::INVALID_MULTISIG_CONFIG");
+                    return Err(PocketError::InvalidMultisigConfig.into());
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    // Whether the administrative actions must be approved by the multisig
+    pub fn is_multisig_enabled(&self) -> bool {
+        return self.multisig_threshold > 0;
+    }
+
+    // Detect if a pubkey was belong to a multisig signer
+    pub fn is_multisig_signer(&self, pubkey: Pubkey) -> bool {
+        return self.is_multisig_enabled() && self.multisig_signers.contains(&pubkey);
+    }
+
+    // Check whether the approvals reach the multisig threshold, only the current signers are counted
+    pub fn is_multisig_approved(&self, approvals: &Vec<Pubkey>) -> bool {
+        return approvals.iter()
+            .filter(|&approval| self.multisig_signers.contains(approval))
+            .count() >= self.multisig_threshold as usize;
+    }
+
+    fn get_role_members(&self, role: PlatformRole) -> &Vec<Pubkey> {
+        return match role {
+            PlatformRole::Admin => &self.admins,
+            PlatformRole::Operator => &self.operators,
+            PlatformRole::Pauser => &self.pausers,
+            PlatformRole::FeeManager => &self.fee_managers,
+            PlatformRole::MintCurator => &self.mint_curators,
+        };
+    }
+
+    fn get_role_members_mut(&mut self, role: PlatformRole) -> &mut Vec<Pubkey> {
+        return match role {
+            PlatformRole::Admin => &mut self.admins,
+            PlatformRole::Operator => &mut self.operators,
+            PlatformRole::Pauser => &mut self.pausers,
+            PlatformRole::FeeManager => &mut self.fee_managers,
+            PlatformRole::MintCurator => &mut self.mint_curators,
+        };
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // The v1 layout, created before the whitelisted markets, fees, roles and version were appended
+    #[derive(AnchorSerialize)]
+    struct PocketPlatformRegistryV1 {
+        owner: Pubkey,
+        was_initialized: bool,
+        bump: u8,
+        allowed_mint_accounts: Vec<MintInfo>,
+        operators: Vec<Pubkey>,
+    }
+
+    fn legacy_registry() -> PocketPlatformRegistryV1 {
+        PocketPlatformRegistryV1 {
+            owner: Pubkey::new_unique(),
+            was_initialized: true,
+            bump: 253,
+            allowed_mint_accounts: vec![MintInfo {
+                is_enabled: true,
+                mint_account: Pubkey::new_unique(),
+                token_account: Pubkey::new_unique(),
+                bump: 1,
+            }],
+            operators: vec![Pubkey::new_unique()],
+        }
+    }
+
+    fn serialize_legacy_registry(legacy: &PocketPlatformRegistryV1) -> Vec<u8> {
+        let mut data = PocketPlatformRegistry::discriminator().to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(LEGACY_REGISTRY_ACCOUNT_SIZE, 0);
+        data
+    }
+
+    #[test]
+    fn should_deserialize_v1_registry() {
+        let legacy = legacy_registry();
+        let data = serialize_legacy_registry(&legacy);
+
+        let registry = PocketPlatformRegistry::try_deserialize(&mut &data[..]).unwrap();
+
+        assert_eq!(registry.owner, legacy.owner);
+        assert_eq!(registry.was_initialized, legacy.was_initialized);
+        assert_eq!(registry.bump, legacy.bump);
+        assert_eq!(registry.allowed_mint_accounts, legacy.allowed_mint_accounts);
+        assert_eq!(registry.operators, legacy.operators);
+        assert_eq!(registry.allowed_markets.len(), 0);
+        assert_eq!(registry.platform_fee_bps, 0);
+        assert_eq!(registry.pending_owner, None);
+        assert_eq!(registry.is_paused, false);
+        assert_eq!(registry.multisig_threshold, 0);
+        assert_eq!(registry.version, 0);
+    }
+
+    #[test]
+    fn should_upgrade_v1_registry() {
+        let legacy = legacy_registry();
+        let mut data = serialize_legacy_registry(&legacy);
+
+        let mut registry = PocketPlatformRegistry::try_deserialize(&mut &data[..]).unwrap();
+        registry.migrate().unwrap();
+        registry.try_serialize(&mut &mut data[..]).unwrap();
+
+        let mut upgraded = PocketPlatformRegistry::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.owner, legacy.owner);
+        assert_eq!(upgraded.allowed_mint_accounts, legacy.allowed_mint_accounts);
+        assert_eq!(upgraded.operators, legacy.operators);
+        assert_eq!(upgraded.version, REGISTRY_VERSION);
+
+        // the registry can't be migrated twice
+        assert!(upgraded.migrate().is_err());
+    }
+
+    #[test]
+    fn should_apply_role_actions() {
+        let mut registry = PocketPlatformRegistry::default();
+        let operator = Pubkey::new_unique();
+
+        let grant_role = AdminAction::GrantRole { role: PlatformRole::Operator, account: operator };
+        let revoke_role = AdminAction::RevokeRole { role: PlatformRole::Operator, account: operator };
+
+        assert!(grant_role.is_timelocked());
+        assert!(!grant_role.is_owner_only());
+        assert!(AdminAction::RevokeRole { role: PlatformRole::Admin, account: operator }.is_owner_only());
+        assert!(AdminAction::UpdateMultisig { signers: vec![], threshold: 0 }.is_owner_only());
+
+        registry.apply_admin_action(&grant_role).unwrap();
+        assert!(registry.is_operator(operator));
+
+        registry.apply_admin_action(&revoke_role).unwrap();
+        assert!(!registry.is_operator(operator));
+    }
+
+    #[test]
+    fn should_apply_market_actions() {
+        let mut registry = PocketPlatformRegistry::default();
+        let market_key = Pubkey::new_unique();
+
+        let add_market = AdminAction::AddMarket {
+            market_key,
+            base_mint_address: Pubkey::new_unique(),
+            quote_mint_address: Pubkey::new_unique(),
+            dex_program: Pubkey::new_unique(),
+        };
+
+        assert_eq!(add_market.required_role(), PlatformRole::MintCurator);
+        assert!(add_market.is_multisig_required());
+
+        registry.apply_admin_action(&add_market).unwrap();
+        assert!(registry.get_market_info(market_key).unwrap().is_enabled);
+
+        registry.apply_admin_action(&AdminAction::SetMarketEnabled { market_key, is_enabled: false }).unwrap();
+        assert!(!registry.get_market_info(market_key).unwrap().is_enabled);
+
+        registry.apply_admin_action(&AdminAction::RemoveMarket { market_key }).unwrap();
+        assert!(registry.get_market_info(market_key).is_none());
+
+        // the market can't be added twice
+        registry.apply_admin_action(&add_market).unwrap();
+        assert!(registry.add_market(MarketInfo { market_key, ..MarketInfo::default() }).is_err());
+    }
+}
@@ -39,6 +39,9 @@ pub enum PriceCondition {
 }
 
 impl PriceCondition {
+    // The serialized size of the largest variant
+    pub const SPACE: usize = 1 + 8 + 8;
+
     pub fn default() -> PriceCondition {
         PriceCondition::Eq { value: 0 }
     }
@@ -78,6 +81,20 @@ impl PriceCondition {
             },
         }
     }
+
+    // Check whether the value fulfills the price condition
+    pub fn is_fulfilled(price: &PriceCondition, value: u64) -> bool {
+        return match *price {
+            PriceCondition::Gt { value: target } => value > target,
+            PriceCondition::Gte { value: target } => value >= target,
+            PriceCondition::Lt { value: target } => value < target,
+            PriceCondition::Lte { value: target } => value <= target,
+            PriceCondition::Eq { value: target } => value == target,
+            PriceCondition::Neq { value: target } => value != target,
+            PriceCondition::Bw { from_value, to_value } => value >= from_value && value <= to_value,
+            PriceCondition::Nbw { from_value, to_value } => !(value >= from_value && value <= to_value),
+        };
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
@@ -114,6 +131,9 @@ pub enum StopCondition {
 }
 
 impl StopCondition {
+    // The serialized size of the largest variant
+    pub const SPACE: usize = 1 + 1 + 8;
+
     pub fn default() -> StopCondition {
         StopCondition::EndTimeReach { is_primary: true, value: 0 }
     }
@@ -177,9 +197,44 @@ impl StopCondition {
     }
 }
 
+// Here we define how the next execution time is computed.
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
-pub struct DateDuration {
-    pub(crate) hours: u64,
+pub enum ScheduleMode {
+    // The next execution follows the actual execution time by the frequency
+    #[default]
+    Relative,
+
+    // The executions are kept on the start time plus a whole number of intervals
+    Anchored,
+}
+
+// Here we define how the executions missed while the operators were down are handled.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum CatchUpPolicy {
+    // The missed executions are skipped
+    #[default]
+    Skip,
+
+    // The volume of the missed executions is swapped in one batch, capped at the volume of max batches
+    Accumulate {
+        max_batches: u64
+    },
+
+    // The missed executions run one by one until the schedule is caught up
+    OneByOne,
+}
+
+impl CatchUpPolicy {
+    // The serialized size of the largest variant
+    pub const SPACE: usize = 1 + 8;
+
+    // Check whether the catch-up policy is valid
+    pub fn is_valid(&self) -> bool {
+        return match *self {
+            CatchUpPolicy::Accumulate { max_batches } => max_batches > 0,
+            _ => true,
+        };
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
@@ -204,6 +259,96 @@ pub enum PocketStatus {
 
     // Declare that the proposal is fully withdrawn by both participant and proposal owner
     Withdrawn,
+
+    // Declare that the Pocket waits for its start condition
+    Waiting,
+}
+
+// Here we define why the pocket was closed.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum PocketCloseReason {
+    // The pocket is not closed, or was closed before the reason was recorded
+    #[default]
+    None,
+
+    // Closed by the owner
+    User,
+
+    // Closed once a stop condition was reached
+    StopConditionReached,
+
+    // Closed once the balance to be swapped ran out
+    BalanceExhausted,
+}
+
+// Here we define the permissions granted to a pocket delegate, the withdrawal is always restricted to the owner.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct DelegatePermissions {
+    // Pause and resume the pocket
+    pub can_update_status: bool,
+
+    // Edit the pocket strategy
+    pub can_edit: bool,
+
+    // Create the vaults and deposit into the pocket
+    pub can_deposit: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum DelegatePermission {
+    UpdateStatus,
+    Edit,
+    Deposit,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct PocketDelegate {
+    pub delegate: Pubkey,
+    pub permissions: DelegatePermissions,
+}
+
+impl PocketDelegate {
+    // The serialized size of a delegate
+    pub const SPACE: usize = 32 + 3;
+}
+
+// Here we define the seeds of the pocket PDA.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum PocketAddressSeed {
+    // Seeded by the free-form id, the pockets created before the owner index read as this variant
+    #[default]
+    Id,
+
+    // Seeded by the creator and the nonce tracked by the owner index
+    OwnerNonce {
+        owner: Pubkey,
+        nonce: u64,
+    },
+
+    // Seeded by the creator and the id, so that the id can't be taken by another user
+    OwnerId {
+        owner: Pubkey,
+    },
+}
+
+impl PocketAddressSeed {
+    // The serialized size of the largest variant
+    pub const SPACE: usize = 1 + 32 + 8;
+}
+
+// Here we define the editable strategy parameters of a pocket.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct PocketStrategy {
+    pub name: String,
+    pub batch_volume: u64,
+    pub start_at: u64,
+    pub frequency: DateDuration,
+    pub buy_condition: Option<PriceCondition>,
+    pub stop_conditions: Vec<StopCondition>,
+    pub schedule_mode: ScheduleMode,
+    pub catch_up_policy: CatchUpPolicy,
+    pub execution_windows: Vec<ExecutionWindow>,
+    pub start_condition: Option<PriceCondition>,
 }
 
 // Here we define the account state that holds the pocket order. Pocket will be the PDA.
@@ -249,8 +394,8 @@ pub struct Pocket {
     // Define the stop condition
     pub stop_conditions: Vec<StopCondition>,
 
-    // Define the frequency
-    pub frequency: DateDuration,
+    // Define the frequency in hours of the v1 pockets, moved to the frequency once migrated
+    pub legacy_frequency_hours: u64,
 
     // Show total deposited base token balance
     pub total_base_deposit_amount: u64,
@@ -269,12 +414,109 @@ pub struct Pocket {
 
     // Next schedule date
     pub next_scheduled_execution_at: u64,
+
+    // Define the tip in lamports paid to the operator for every executed batch, fallback to the platform default
+    pub keeper_tip_amount: u64,
+
+    // Show the reserved lamports for operator tips
+    pub keeper_tip_balance: u64,
+
+    // Define the layout version, the v1 accounts created before versioning read as zero
+    pub version: u8,
+
+    // Define the proposed owner, who must accept the ownership before it is transferred
+    pub pending_owner: Option<Pubkey>,
+
+    // Define the delegates who manage the pocket on behalf of the owner
+    pub delegates: Vec<PocketDelegate>,
+
+    // Define the seeds of the pocket PDA
+    pub address_seed: PocketAddressSeed,
+
+    // Define why the pocket was closed
+    pub close_reason: PocketCloseReason,
+
+    // Define whether a deposit restarts the pocket which was closed because its balance ran out
+    pub auto_restart_on_deposit: bool,
+
+    // Define the frequency, only unset on the v1 pockets which were not migrated yet
+    pub frequency: Option<DateDuration>,
+
+    // Define whether the schedule follows the actual execution time or the start time
+    pub schedule_mode: ScheduleMode,
+
+    // Define how the missed executions are handled
+    pub catch_up_policy: CatchUpPolicy,
+
+    // Show the number of the missed executions which were skipped
+    pub skipped_batch_amount: u64,
+
+    // Define the UTC time ranges in which the pocket can be executed, empty to execute at any time
+    pub execution_windows: Vec<ExecutionWindow>,
+
+    // Define the price condition which starts the waiting pocket
+    pub start_condition: Option<PriceCondition>,
 }
 
 impl Pocket {
-    // Check whether the pocket is open for depositing
+    // Compute the account space, the optional fields are reserved at their largest size
+    pub fn space(id_length: usize, name_length: usize, stop_conditions_length: usize, delegates_length: usize) -> usize {
+        return 8 // discriminator
+            + 4 + id_length // id
+            + 1 // bump
+            + 32 // owner
+            + 4 + name_length // name
+            + 1 // status
+            + 32 * 3 // base token, quote token and market
+            + 8 // batch volume
+            + 8 // start at
+            + 1 // side
+            + 1 + PriceCondition::SPACE // buy condition
+            + 4 + stop_conditions_length * StopCondition::SPACE // stop conditions
+            + 8 // legacy frequency hours
+            + 8 * 6 // deposit amounts, balances, executed batch amount and next schedule
+            + 8 * 2 // keeper tip amount and balance
+            + 1 // version
+            + 1 + 32 // pending owner
+            + 4 + delegates_length * PocketDelegate::SPACE // delegates
+            + PocketAddressSeed::SPACE // address seed
+            + 1 // close reason
+            + 1 // auto restart on deposit
+            + 1 + DateDuration::SPACE // frequency
+            + 1 // schedule mode
+            + CatchUpPolicy::SPACE // catch-up policy
+            + 8 // skipped batch amount
+            + 4 + MAX_EXECUTION_WINDOWS * ExecutionWindow::SPACE // execution windows
+            + 1 + PriceCondition::SPACE; // start condition
+    }
+
+    // Compute the space required by the current pocket data
+    pub fn current_space(&self) -> usize {
+        return Pocket::space(self.id.len(), self.name.len(), self.stop_conditions.len(), self.delegates.len());
+    }
+
+    // Upgrade the legacy layout in place, the appended fields are zero-initialized by the realloc
+    pub fn migrate(&mut self) -> Result<()> {
+        if self.version >= POCKET_VERSION {
+            msg!("ERROR::POCKET::ALREADY_MIGRATED");
+            return Err(PocketError::AlreadyMigrated.into());
+        }
+
+        self.version = POCKET_VERSION;
+
+        // the hourly frequency of the v1 layout is moved to the frequency
+        if self.frequency.is_none() {
+            self.frequency = Some(DateDuration::Hours { hours: self.legacy_frequency_hours });
+            self.legacy_frequency_hours = 0;
+        }
+
+        Ok(())
+    }
+
+    // Check whether the pocket is open for depositing, the pocket which ran out of balance can be topped up to restart
     pub fn is_able_to_deposit(&self) -> bool {
-        return self.status != PocketStatus::Closed && self.status != PocketStatus::Withdrawn;
+        return (self.status != PocketStatus::Closed && self.status != PocketStatus::Withdrawn)
+            || self.is_able_to_restart_on_deposit();
     }
 
     // Check whether the pocket is able to close
@@ -287,21 +529,378 @@ impl Pocket {
         return self.status == PocketStatus::Closed;
     }
 
+    // Check whether the pocket is able to withdraw a part of its balance
+    pub fn is_able_to_withdraw_partially(&self) -> bool {
+        return self.status != PocketStatus::Withdrawn;
+    }
+
+    // Deduct the withdrawn amount from the balance, the total deposit is reduced as well so that the spent amount is unchanged
+    pub fn withdraw_partially(&mut self, mint_address: Pubkey, amount: u64) -> Result<()> {
+        assert_eq!(amount > 0, true, "WITHDRAW_AMOUNT_IS_NOT_VALID");
+
+        if mint_address == self.base_token_mint_address {
+            assert_eq!(amount <= self.base_token_balance, true, "INSUFFICIENT_BALANCE");
+
+            self.base_token_balance = self.base_token_balance - amount;
+            self.total_base_deposit_amount = self.total_base_deposit_amount.saturating_sub(amount);
+
+            return Ok(());
+        }
+
+        if mint_address == self.quote_token_mint_address {
+            assert_eq!(amount <= self.quote_token_balance, true, "INSUFFICIENT_BALANCE");
+
+            self.quote_token_balance = self.quote_token_balance - amount;
+            self.total_quote_deposit_amount = self.total_quote_deposit_amount.saturating_sub(amount);
+
+            return Ok(());
+        }
+
+        msg!("ERROR::POCKET::INVALID_WITHDRAW_MINT");
+        return Err(PocketError::InvalidTokenAccount.into());
+    }
+
+    // Check whether the waiting pocket is able to start at the quoted amount
+    pub fn is_able_to_start(&self, quoted_amount: u64) -> bool {
+        return self.status == PocketStatus::Waiting
+            && self.start_condition.is_some()
+            && PriceCondition::is_fulfilled(&self.start_condition.unwrap(), quoted_amount);
+    }
+
+    // Start the waiting pocket, the first batch is due in the next execution window.
+    // The start condition is consumed, so that the pocket doesn't wait again once resumed.
+    pub fn start(&mut self, now: u64) {
+        self.status = PocketStatus::Active;
+        self.start_condition = None;
+        self.next_scheduled_execution_at = self.roll_to_execution_window(now.max(self.start_at));
+    }
+
     // Check whether the pocket is able to restart
     pub fn is_able_to_restart(&self) -> bool {
         return self.status == PocketStatus::Paused;
     }
 
+    // The status of the restarted pocket, it waits again while its start condition is not reached
+    pub fn get_restart_status(&self) -> PocketStatus {
+        return if self.start_condition.is_some() { PocketStatus::Waiting } else { PocketStatus::Active };
+    }
+
+    // Run the paused pocket again, the executions missed during the pause are not caught up
+    pub fn restart(&mut self, now: u64) {
+        self.status = self.get_restart_status();
+        self.reschedule(now);
+    }
+
+    // Check whether the closed pocket is able to run again, new stop conditions are required
+    pub fn is_able_to_reopen(&self) -> bool {
+        return self.status == PocketStatus::Closed;
+    }
+
+    // Check whether a deposit restarts the pocket
+    pub fn is_able_to_restart_on_deposit(&self) -> bool {
+        return self.status == PocketStatus::Closed
+            && self.close_reason == PocketCloseReason::BalanceExhausted
+            && self.auto_restart_on_deposit;
+    }
+
+    // Close the pocket for the reason
+    pub fn mark_closed(&mut self, reason: PocketCloseReason) {
+        self.status = PocketStatus::Closed;
+        self.close_reason = reason;
+    }
+
+    // Run the closed pocket again, the executions missed while closed are not caught up
+    pub fn reopen(&mut self, now: u64) {
+        self.restart(now);
+        self.close_reason = PocketCloseReason::None;
+    }
+
+    // Check whether the balance to be swapped ran out
+    pub fn is_balance_exhausted(&self) -> bool {
+        return match self.side {
+            TradeSide::Buy => self.quote_token_balance == 0,
+            TradeSide::Sell => self.base_token_balance == 0,
+        };
+    }
+
+    // Check whether the deposited token is the one to be swapped
+    pub fn is_swapped_token(&self, mint_address: Pubkey) -> bool {
+        return match self.side {
+            TradeSide::Buy => mint_address == self.quote_token_mint_address,
+            TradeSide::Sell => mint_address == self.base_token_mint_address,
+        };
+    }
+
+    // Check whether any stop condition is reached
+    pub fn is_stop_condition_reached(&self, now: u64) -> bool {
+        return self.stop_conditions.iter().any(|condition| match *condition {
+            StopCondition::EndTimeReach { value, .. } => value <= now,
+            StopCondition::BaseTokenAmountReach { value, .. } => value <= self.base_token_balance,
+            StopCondition::QuoteTokenAmountReach { value, .. } => value <= self.quote_token_balance,
+            StopCondition::SpentBaseTokenAmountReach { value, .. } => {
+                value <= self.total_base_deposit_amount.saturating_sub(self.base_token_balance)
+            },
+            StopCondition::SpentQuoteTokenAmountReach { value, .. } => {
+                value <= self.total_quote_deposit_amount.saturating_sub(self.quote_token_balance)
+            },
+            StopCondition::BatchAmountReach { value, .. } => value <= self.executed_batch_amount,
+        });
+    }
+
     // Check whether the pocket is able to pause
     pub fn is_able_to_pause(&self) -> bool {
-        return self.status == PocketStatus::Active;
+        return self.status == PocketStatus::Active || self.status == PocketStatus::Waiting;
+    }
+
+    // Check whether the pocket strategy is able to be edited, the closed pocket is edited to be reopened
+    pub fn is_able_to_edit(&self) -> bool {
+        return self.status == PocketStatus::Paused
+            || self.status == PocketStatus::Closed
+            || self.status == PocketStatus::Waiting;
+    }
+
+    // Move the control of the pocket and its vaults to the new owner, the delegates of the previous owner are revoked
+    pub fn transfer_ownership(&mut self, new_owner: Pubkey) {
+        self.owner = new_owner;
+        self.pending_owner = None;
+        self.delegates = vec![];
+    }
+
+    // Check whether the signer is the owner or a delegate holding the permission
+    pub fn has_permission(&self, signer: Pubkey, permission: DelegatePermission) -> bool {
+        if self.owner == signer {
+            return true;
+        }
+
+        return self.delegates.iter()
+            .find(|&pocket_delegate| pocket_delegate.delegate == signer)
+            .map(|pocket_delegate| match permission {
+                DelegatePermission::UpdateStatus => pocket_delegate.permissions.can_update_status,
+                DelegatePermission::Edit => pocket_delegate.permissions.can_edit,
+                DelegatePermission::Deposit => pocket_delegate.permissions.can_deposit,
+            })
+            .unwrap_or(false);
+    }
+
+    // Check whether the signer is the owner or a delegate
+    pub fn is_owner_or_delegate(&self, signer: Pubkey) -> bool {
+        return self.owner == signer
+            || self.delegates.iter().any(|pocket_delegate| pocket_delegate.delegate == signer);
+    }
+
+    // Add or update a delegate
+    pub fn set_delegate(&mut self, delegate: Pubkey, permissions: DelegatePermissions) -> Result<()> {
+        match self.delegates.iter_mut().find(|pocket_delegate| pocket_delegate.delegate == delegate) {
+            Some(pocket_delegate) => {
+                pocket_delegate.permissions = permissions;
+            }
+
+            None => {
+                if self.delegates.len() >= MAX_POCKET_DELEGATES {
+                    msg!("ERROR::POCKET::TOO_MANY_DELEGATES");
+                    return Err(PocketError::TooManyDelegates.into());
+                }
+
+                self.delegates.push(PocketDelegate { delegate, permissions });
+            }
+        }
+
+        Ok(())
+    }
+
+    // Remove a delegate
+    pub fn remove_delegate(&mut self, delegate: Pubkey) -> Result<()> {
+        if !self.delegates.iter().any(|pocket_delegate| pocket_delegate.delegate == delegate) {
+            msg!("ERROR::POCKET::DELEGATE_NOT_EXISTED");
+            return Err(PocketError::DelegateNotExisted.into());
+        }
+
+        self.delegates.retain(|pocket_delegate| pocket_delegate.delegate != delegate);
+
+        Ok(())
+    }
+
+    // Get the editable strategy parameters
+    pub fn get_strategy(&self) -> PocketStrategy {
+        return PocketStrategy {
+            name: self.name.clone(),
+            batch_volume: self.batch_volume,
+            start_at: self.start_at,
+            frequency: self.get_frequency(),
+            buy_condition: self.buy_condition,
+            stop_conditions: self.stop_conditions.clone(),
+            schedule_mode: self.schedule_mode,
+            catch_up_policy: self.catch_up_policy,
+            execution_windows: self.execution_windows.clone(),
+            start_condition: self.start_condition,
+        };
+    }
+
+    // Seeds to sign with the pocket PDA
+    pub fn signer_seeds(&self) -> Vec<Vec<u8>> {
+        let mut seeds = match self.address_seed {
+            PocketAddressSeed::Id => vec![
+                POCKET_SEED.to_vec(),
+                self.id.as_bytes().to_vec(),
+            ],
+            PocketAddressSeed::OwnerNonce { owner, nonce } => vec![
+                POCKET_NONCE_SEED.to_vec(),
+                owner.to_bytes().to_vec(),
+                nonce.to_le_bytes().to_vec(),
+            ],
+            PocketAddressSeed::OwnerId { owner } => vec![
+                POCKET_SEED.to_vec(),
+                owner.to_bytes().to_vec(),
+                self.id.as_bytes().to_vec(),
+            ],
+        };
+
+        seeds.push(vec![self.bump]);
+        seeds
+    }
+
+    // Derive the pocket PDA from its seeds
+    pub fn derive_address(&self) -> Pubkey {
+        pocket_signer!(self, signer);
+
+        return Pubkey::create_program_address(signer[0], &crate::ID).unwrap_or_default();
+    }
+
+    // Find the token vault of the pocket for the mint
+    pub fn find_token_vault_address(pocket_address: Pubkey, mint_address: Pubkey) -> Pubkey {
+        return Pubkey::find_program_address(
+            &[TOKEN_ACCOUNT_SEED, pocket_address.as_ref(), mint_address.as_ref()],
+            &crate::ID,
+        ).0;
+    }
+
+    // Get the frequency of the pocket, the v1 pockets run hourly until migrated
+    pub fn get_frequency(&self) -> DateDuration {
+        return self.frequency.unwrap_or(DateDuration::Hours { hours: self.legacy_frequency_hours });
+    }
+
+    // Compute the next execution time after an execution at now
+    pub fn get_next_execution_at(&self, now: u64) -> u64 {
+        let frequency = self.get_frequency();
+
+        return match self.schedule_mode {
+            ScheduleMode::Relative => frequency.next_execution_at(now),
+            ScheduleMode::Anchored => frequency.next_anchored_execution_at(self.start_at, now),
+        };
+    }
+
+    // Count the executions missed before now, the execution which is due is not counted
+    pub fn get_missed_batch_amount(&self, now: u64) -> u64 {
+        let due_at = self.next_scheduled_execution_at.max(self.start_at);
+        let mut next_at = self.get_next_execution_at(due_at);
+
+        if next_at > now {
+            return 0;
+        }
+
+        if let Some(interval) = self.get_frequency().interval() {
+            return (now - next_at) / interval.max(1) + 1;
+        }
+
+        let mut missed_batch_amount = 0;
+
+        while next_at <= now {
+            missed_batch_amount = missed_batch_amount + 1;
+            next_at = self.get_next_execution_at(next_at);
+        }
+
+        return missed_batch_amount;
+    }
+
+    // Plan the execution at now, returns the number of batches swapped at once and the number of skipped batches
+    pub fn plan_catch_up(&self, now: u64) -> (u64, u64) {
+        let missed_batch_amount = self.get_missed_batch_amount(now);
+
+        return match self.catch_up_policy {
+            CatchUpPolicy::Skip => (1, missed_batch_amount),
+
+            CatchUpPolicy::Accumulate { max_batches } => {
+                let batch_amount = (missed_batch_amount + 1).min(max_batches.max(1));
+                (batch_amount, missed_batch_amount + 1 - batch_amount)
+            }
+
+            CatchUpPolicy::OneByOne => (1, 0),
+        };
+    }
+
+    // Compute the next execution time after an execution at now, the missed executions are due right away when run one by one.
+    // The execution is rolled forward to the next execution window.
+    pub fn get_next_scheduled_execution_at(&self, now: u64) -> u64 {
+        let next_at = if self.catch_up_policy == CatchUpPolicy::OneByOne && self.get_missed_batch_amount(now) > 0 {
+            self.get_next_execution_at(self.next_scheduled_execution_at.max(self.start_at))
+        } else {
+            self.get_next_execution_at(now)
+        };
+
+        return self.roll_to_execution_window(next_at);
+    }
+
+    // Reschedule the next execution once the schedule is edited, the pocket which didn't start yet runs at its start time
+    pub fn reschedule(&mut self, now: u64) {
+        let next_at = if self.start_at > now { self.start_at } else { self.get_next_execution_at(now) };
+        self.next_scheduled_execution_at = self.roll_to_execution_window(next_at);
+    }
+
+    // Check whether the pocket can be executed at the timestamp
+    pub fn is_in_execution_window(&self, timestamp: u64) -> bool {
+        return self.execution_windows.is_empty()
+            || self.execution_windows.iter().any(|window| window.contains(timestamp));
+    }
+
+    // Find the earliest time from the timestamp in an execution window
+    pub fn roll_to_execution_window(&self, timestamp: u64) -> u64 {
+        return self.execution_windows
+            .iter()
+            .map(|window| window.next_opening_at(timestamp))
+            .min()
+            .unwrap_or(timestamp);
     }
 
     // Check whether the pocket is able to swap
     pub fn is_ready_to_swap(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp as u64;
+
         return self.status == PocketStatus::Active
-            && self.start_at <= Clock::get().unwrap().unix_timestamp as u64
-            && self.next_scheduled_execution_at <= Clock::get().unwrap().unix_timestamp as u64
+            && self.start_at <= now
+            && self.next_scheduled_execution_at <= now
+            && self.is_in_execution_window(now)
+    }
+
+    // Get the tip paid to the operator for the next execution
+    pub fn get_keeper_tip_amount(&self, default_keeper_tip_amount: u64) -> u64 {
+        let keeper_tip_amount = if self.keeper_tip_amount > 0 {
+            self.keeper_tip_amount
+        } else {
+            default_keeper_tip_amount
+        };
+
+        return keeper_tip_amount.min(self.keeper_tip_balance);
+    }
+
+    // Check whether the start time is not in the past, only checked when the start time is set
+    pub fn validate_start_at(&self) -> Result<()> {
+        assert_eq!(self.start_at >= Clock::get().unwrap().unix_timestamp as u64, true, "TIMESTAMP_IS_NOT_VALID");
+
+        Ok(())
+    }
+
+    // Check the id format of a new pocket, the legacy pockets keep their ids
+    pub fn validate_id_format(&self) -> Result<()> {
+        let is_valid = !self.id.is_empty()
+            && self.id.len() <= MAX_POCKET_ID_LENGTH
+            && self.id.bytes().all(|x| x.is_ascii_alphanumeric() || x == b'-' || x == b'_');
+
+        if !is_valid {
+            msg!("ERROR::POCKET::INVALID_ID");
+            return Err(PocketError::InvalidPocketId.into());
+        }
+
+        Ok(())
     }
 
     // Check whether the pocket data is valid
@@ -311,13 +910,41 @@ impl Pocket {
         assert_ne!(pocket.name, String::default(), "POCKET_NAME_IS_NOT_VALID");
         assert_ne!(pocket.id, String::default(), "POCKET_ID_IS_NOT_VALID");
 
+        if pocket.id.len() > MAX_POCKET_ID_LENGTH {
+            msg!("ERROR::POCKET::ID_TOO_LONG");
+            return Err(PocketError::PocketIdTooLong.into());
+        }
+
+        if pocket.name.len() > MAX_POCKET_NAME_LENGTH {
+            msg!("ERROR::POCKET::NAME_TOO_LONG");
+            return Err(PocketError::PocketNameTooLong.into());
+        }
+
+        if pocket.stop_conditions.len() > MAX_STOP_CONDITIONS {
+            msg!("ERROR::POCKET::TOO_MANY_STOP_CONDITIONS");
+            return Err(PocketError::TooManyStopConditions.into());
+        }
+
         assert_ne!(pocket.owner, Pubkey::default(), "OWNER_IS_NOT_VALID");
         assert_ne!(pocket.base_token_mint_address, Pubkey::default(), "BASE_MINT_IS_NOT_VALID");
         assert_ne!(pocket.quote_token_mint_address, Pubkey::default(), "QUOTE_MINT_IS_NOT_VALID");
         assert_ne!(pocket.market_key, Pubkey::default(), "MARKET_KEY_IS_NOT_VALID");
 
-        assert_eq!(pocket.start_at >= Clock::get().unwrap().unix_timestamp as u64, true, "TIMESTAMP_IS_NOT_VALID");
-        assert_eq!(pocket.frequency.hours > 0, true, "FREQUENCY_IS_NOT_VALID");
+        assert_eq!(pocket.get_frequency().is_valid(), true, "FREQUENCY_IS_NOT_VALID");
+        assert_eq!(pocket.catch_up_policy.is_valid(), true, "CATCH_UP_POLICY_IS_NOT_VALID");
+
+        if pocket.start_condition.is_some() {
+            assert_eq!(PriceCondition::is_valid(&pocket.start_condition.unwrap()), true, "START_CONDITION_IS_NOT_VALID");
+        }
+
+        if pocket.execution_windows.len() > MAX_EXECUTION_WINDOWS {
+            msg!("ERROR::POCKET::TOO_MANY_EXECUTION_WINDOWS");
+            return Err(PocketError::TooManyExecutionWindows.into());
+        }
+
+        for window in pocket.execution_windows.iter() {
+            assert_eq!(window.is_valid(), true, "EXECUTION_WINDOW_IS_NOT_VALID");
+        }
         assert_eq!(pocket.batch_volume > 0, true, "BATCH_VOLUME_IS_NOT_VALID");
 
         if pocket.buy_condition.unwrap_or(PriceCondition::default()) != PriceCondition::default() {
@@ -338,4 +965,475 @@ impl Pocket {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // The v1 layout, created before the keeper tip and version fields were appended
+    #[derive(AnchorSerialize)]
+    struct PocketV1 {
+        id: String,
+        bump: u8,
+        owner: Pubkey,
+        name: String,
+        status: PocketStatus,
+        base_token_mint_address: Pubkey,
+        quote_token_mint_address: Pubkey,
+        market_key: Pubkey,
+        batch_volume: u64,
+        start_at: u64,
+        side: TradeSide,
+        buy_condition: Option<PriceCondition>,
+        stop_conditions: Vec<StopCondition>,
+        frequency_hours: u64,
+        total_base_deposit_amount: u64,
+        total_quote_deposit_amount: u64,
+        base_token_balance: u64,
+        quote_token_balance: u64,
+        executed_batch_amount: u64,
+        next_scheduled_execution_at: u64,
+    }
+
+    fn legacy_pocket() -> PocketV1 {
+        PocketV1 {
+            id: String::from("pocket-v1"),
+            bump: 254,
+            owner: Pubkey::new_unique(),
+            name: String::from("Legacy pocket"),
+            status: PocketStatus::Paused,
+            base_token_mint_address: Pubkey::new_unique(),
+            quote_token_mint_address: Pubkey::new_unique(),
+            market_key: Pubkey::new_unique(),
+            batch_volume: 1_000,
+            start_at: 1_670_000_000,
+            side: TradeSide::Sell,
+            buy_condition: Some(PriceCondition::Gt { value: 42 }),
+            stop_conditions: vec![StopCondition::EndTimeReach { value: 1_680_000_000, is_primary: true }],
+            frequency_hours: 24,
+            total_base_deposit_amount: 10_000,
+            total_quote_deposit_amount: 0,
+            base_token_balance: 9_000,
+            quote_token_balance: 7,
+            executed_batch_amount: 1,
+            next_scheduled_execution_at: 1_670_086_400,
+        }
+    }
+
+    fn serialize_legacy_pocket(legacy: &PocketV1) -> Vec<u8> {
+        let mut data = Pocket::discriminator().to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data
+    }
+
+    fn assert_legacy_fields(pocket: &Pocket, legacy: &PocketV1) {
+        assert_eq!(pocket.id, legacy.id);
+        assert_eq!(pocket.bump, legacy.bump);
+        assert_eq!(pocket.owner, legacy.owner);
+        assert_eq!(pocket.name, legacy.name);
+        assert_eq!(pocket.status, legacy.status);
+        assert_eq!(pocket.base_token_mint_address, legacy.base_token_mint_address);
+        assert_eq!(pocket.quote_token_mint_address, legacy.quote_token_mint_address);
+        assert_eq!(pocket.market_key, legacy.market_key);
+        assert_eq!(pocket.batch_volume, legacy.batch_volume);
+        assert_eq!(pocket.start_at, legacy.start_at);
+        assert_eq!(pocket.side, legacy.side);
+        assert_eq!(pocket.buy_condition, legacy.buy_condition);
+        assert_eq!(pocket.stop_conditions, legacy.stop_conditions);
+        assert_eq!(pocket.get_frequency(), DateDuration::Hours { hours: legacy.frequency_hours });
+        assert_eq!(pocket.total_base_deposit_amount, legacy.total_base_deposit_amount);
+        assert_eq!(pocket.total_quote_deposit_amount, legacy.total_quote_deposit_amount);
+        assert_eq!(pocket.base_token_balance, legacy.base_token_balance);
+        assert_eq!(pocket.quote_token_balance, legacy.quote_token_balance);
+        assert_eq!(pocket.executed_batch_amount, legacy.executed_batch_amount);
+        assert_eq!(pocket.next_scheduled_execution_at, legacy.next_scheduled_execution_at);
+    }
+
+    #[test]
+    fn should_deserialize_v1_pocket() {
+        let legacy = legacy_pocket();
+        let mut data = serialize_legacy_pocket(&legacy);
+        data.resize(LEGACY_POCKET_ACCOUNT_SIZE, 0);
+
+        let pocket = Pocket::try_deserialize(&mut &data[..]).unwrap();
+
+        assert_legacy_fields(&pocket, &legacy);
+        assert_eq!(pocket.keeper_tip_amount, 0);
+        assert_eq!(pocket.keeper_tip_balance, 0);
+        assert_eq!(pocket.version, 0);
+    }
+
+    #[test]
+    fn should_upgrade_v1_pocket() {
+        let legacy = legacy_pocket();
+
+        // an account allocated with the exact v1 size can't hold the appended fields before the realloc
+        let mut data = serialize_legacy_pocket(&legacy);
+        assert!(Pocket::try_deserialize(&mut &data[..]).is_err());
+
+        data.resize(LEGACY_POCKET_ACCOUNT_SIZE, 0);
+
+        let mut pocket = Pocket::try_deserialize(&mut &data[..]).unwrap();
+        pocket.migrate().unwrap();
+        pocket.try_serialize(&mut &mut data[..]).unwrap();
+
+        let upgraded = Pocket::try_deserialize(&mut &data[..]).unwrap();
+        assert_legacy_fields(&upgraded, &legacy);
+        assert_eq!(upgraded.version, POCKET_VERSION);
+        assert_eq!(upgraded.frequency, Some(DateDuration::Hours { hours: legacy.frequency_hours }));
+        assert_eq!(upgraded.legacy_frequency_hours, 0);
+
+        // the pocket can't be migrated twice
+        let mut upgraded = upgraded;
+        assert!(upgraded.migrate().is_err());
+    }
+
+    #[test]
+    fn should_upgrade_v2_pocket() {
+        // the v2 pockets were allocated with the exact space, before the pending owner was appended
+        let pocket = Pocket {
+            id: String::from("pocket-v2"),
+            name: String::from("Sized pocket"),
+            owner: Pubkey::new_unique(),
+            version: 2,
+            ..Pocket::default()
+        };
+
+        let mut data: Vec<u8> = Vec::new();
+        pocket.try_serialize(&mut data).unwrap();
+
+        // drop the empty pending owner, delegates, address seed, close reason, auto restart flag, frequency, schedule mode,
+        // catch-up policy, skipped batch amount, execution windows and start condition
+        data.truncate(data.len() - 1 - 4 - 1 - 1 - 1 - 1 - 1 - 1 - 8 - 4 - 1);
+
+        assert!(Pocket::try_deserialize(&mut &data[..]).is_err());
+
+        // the migration pads the account before the deserialization
+        data.resize(data.len() + Pocket::space(0, 0, 0, 0), 0);
+
+        let mut upgraded = Pocket::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.owner, pocket.owner);
+        assert_eq!(upgraded.version, 2);
+        assert_eq!(upgraded.pending_owner, None);
+
+        upgraded.migrate().unwrap();
+        assert_eq!(upgraded.version, POCKET_VERSION);
+    }
+
+    #[test]
+    fn should_compute_pocket_space() {
+        let pocket = Pocket {
+            id: "a".repeat(MAX_POCKET_ID_LENGTH),
+            name: "b".repeat(MAX_POCKET_NAME_LENGTH),
+            buy_condition: Some(PriceCondition::Nbw { from_value: 1, to_value: 2 }),
+            stop_conditions: vec![StopCondition::default(); MAX_STOP_CONDITIONS],
+            version: POCKET_VERSION,
+            pending_owner: Some(Pubkey::new_unique()),
+            delegates: vec![PocketDelegate::default(); MAX_POCKET_DELEGATES],
+            address_seed: PocketAddressSeed::OwnerNonce { owner: Pubkey::new_unique(), nonce: u64::MAX },
+            frequency: Some(DateDuration::Minutes { minutes: u64::MAX }),
+            catch_up_policy: CatchUpPolicy::Accumulate { max_batches: u64::MAX },
+            execution_windows: vec![ExecutionWindow::default(); MAX_EXECUTION_WINDOWS],
+            start_condition: Some(PriceCondition::Bw { from_value: 1, to_value: 2 }),
+            ..Pocket::default()
+        };
+
+        let mut data: Vec<u8> = Vec::new();
+        pocket.try_serialize(&mut data).unwrap();
+
+        assert_eq!(pocket.current_space(), data.len());
+    }
+
+    #[test]
+    fn should_derive_pocket_address() {
+        let owner = Pubkey::new_unique();
+
+        let (id_address, id_bump) = Pubkey::find_program_address(
+            &[POCKET_SEED, b"pocket-id"],
+            &crate::ID,
+        );
+        let (nonce_address, nonce_bump) = Pubkey::find_program_address(
+            &[POCKET_NONCE_SEED, owner.as_ref(), &7u64.to_le_bytes()],
+            &crate::ID,
+        );
+
+        let id_pocket = Pocket {
+            id: String::from("pocket-id"),
+            bump: id_bump,
+            ..Pocket::default()
+        };
+        assert_eq!(id_pocket.derive_address(), id_address);
+
+        let nonce_pocket = Pocket {
+            id: String::from("pocket-id"),
+            bump: nonce_bump,
+            address_seed: PocketAddressSeed::OwnerNonce { owner, nonce: 7 },
+            ..Pocket::default()
+        };
+        assert_eq!(nonce_pocket.derive_address(), nonce_address);
+
+        let (owner_id_address, owner_id_bump) = Pubkey::find_program_address(
+            &[POCKET_SEED, owner.as_ref(), b"pocket-id"],
+            &crate::ID,
+        );
+
+        let owner_id_pocket = Pocket {
+            id: String::from("pocket-id"),
+            bump: owner_id_bump,
+            address_seed: PocketAddressSeed::OwnerId { owner },
+            ..Pocket::default()
+        };
+        assert_eq!(owner_id_pocket.derive_address(), owner_id_address);
+
+        // the same id of another owner is another pocket
+        assert_ne!(owner_id_address, id_address);
+        assert_ne!(
+            owner_id_address,
+            Pubkey::find_program_address(&[POCKET_SEED, Pubkey::new_unique().as_ref(), b"pocket-id"], &crate::ID).0
+        );
+    }
+
+    #[test]
+    fn should_validate_id_format() {
+        let longest_id = "a".repeat(MAX_POCKET_ID_LENGTH);
+        let too_long_id = "a".repeat(MAX_POCKET_ID_LENGTH + 1);
+
+        for id in ["pocket-1", "Pocket_2", "a", longest_id.as_str()] {
+            let pocket = Pocket { id: String::from(id), ..Pocket::default() };
+            assert!(pocket.validate_id_format().is_ok(), "{}", id);
+        }
+
+        for id in ["", "pocket 1", "pocket/1", "pocket.1", "pöcket", too_long_id.as_str()] {
+            let pocket = Pocket { id: String::from(id), ..Pocket::default() };
+            assert!(pocket.validate_id_format().is_err(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn should_evaluate_stop_conditions() {
+        let mut pocket = Pocket {
+            side: TradeSide::Buy,
+            total_quote_deposit_amount: 1_000,
+            quote_token_balance: 400,
+            executed_batch_amount: 3,
+            stop_conditions: vec![
+                StopCondition::EndTimeReach { is_primary: false, value: 2_000 },
+                StopCondition::SpentQuoteTokenAmountReach { is_primary: false, value: 700 },
+            ],
+            ..Pocket::default()
+        };
+
+        assert!(!pocket.is_stop_condition_reached(1_000));
+        assert!(pocket.is_stop_condition_reached(2_000));
+
+        pocket.quote_token_balance = 300;
+        assert!(pocket.is_stop_condition_reached(1_000));
+
+        pocket.stop_conditions = vec![StopCondition::BatchAmountReach { is_primary: true, value: 4 }];
+        assert!(!pocket.is_stop_condition_reached(1_000));
+    }
+
+    #[test]
+    fn should_only_restart_exhausted_pocket_on_deposit() {
+        let mut pocket = Pocket {
+            side: TradeSide::Sell,
+            base_token_mint_address: Pubkey::new_unique(),
+            quote_token_mint_address: Pubkey::new_unique(),
+            auto_restart_on_deposit: true,
+            ..Pocket::default()
+        };
+
+        assert!(pocket.is_balance_exhausted());
+        assert!(pocket.is_swapped_token(pocket.base_token_mint_address));
+        assert!(!pocket.is_swapped_token(pocket.quote_token_mint_address));
+
+        pocket.mark_closed(PocketCloseReason::StopConditionReached);
+        assert!(!pocket.is_able_to_deposit());
+        assert!(!pocket.is_able_to_restart_on_deposit());
+
+        pocket.mark_closed(PocketCloseReason::BalanceExhausted);
+        assert!(pocket.is_able_to_deposit());
+        assert!(pocket.is_able_to_restart_on_deposit());
+
+        pocket.auto_restart_on_deposit = false;
+        assert!(!pocket.is_able_to_deposit());
+
+        pocket.reopen(0);
+        assert_eq!(pocket.status, PocketStatus::Active);
+        assert_eq!(pocket.close_reason, PocketCloseReason::None);
+    }
+
+    #[test]
+    fn should_not_catch_up_the_pause() {
+        let start_at = 1_704_099_600;
+
+        let mut pocket = Pocket {
+            start_at,
+            frequency: Some(DateDuration::Hours { hours: 1 }),
+            catch_up_policy: CatchUpPolicy::Accumulate { max_batches: 10 },
+            next_scheduled_execution_at: start_at + 3600,
+            status: PocketStatus::Paused,
+            ..Pocket::default()
+        };
+
+        // resumed a day later
+        let now = start_at + 24 * 3600;
+        pocket.restart(now);
+
+        assert_eq!(pocket.status, PocketStatus::Active);
+        assert_eq!(pocket.next_scheduled_execution_at, now + 3600);
+        assert_eq!(pocket.get_missed_batch_amount(now + 3600), 0);
+    }
+
+    #[test]
+    fn should_compute_next_execution_by_schedule_mode() {
+        let start_at = 1_704_099_600;
+
+        let mut pocket = Pocket {
+            start_at,
+            frequency: Some(DateDuration::Hours { hours: 24 }),
+            ..Pocket::default()
+        };
+
+        // a late execution pushes the relative schedule back
+        let executed_at = start_at + 2 * 3600;
+
+        assert_eq!(pocket.schedule_mode, ScheduleMode::Relative);
+        assert_eq!(pocket.get_next_execution_at(executed_at), executed_at + 86400);
+
+        pocket.schedule_mode = ScheduleMode::Anchored;
+        assert_eq!(pocket.get_next_execution_at(executed_at), start_at + 86400);
+
+        pocket.frequency = Some(DateDuration::Minutes { minutes: 30 });
+        assert_eq!(pocket.get_next_execution_at(executed_at + 60), start_at + 2 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn should_plan_catch_up_of_missed_executions() {
+        let start_at = 1_704_099_600;
+
+        let mut pocket = Pocket {
+            start_at,
+            frequency: Some(DateDuration::Hours { hours: 1 }),
+            next_scheduled_execution_at: start_at + 3600,
+            ..Pocket::default()
+        };
+
+        // the execution is on time
+        assert_eq!(pocket.get_missed_batch_amount(start_at + 3600 + 59), 0);
+        assert_eq!(pocket.plan_catch_up(start_at + 3600 + 59), (1, 0));
+
+        // the operators were down for 3 hours and a half, 3 executions were missed
+        let now = start_at + 4 * 3600 + 1800;
+        assert_eq!(pocket.get_missed_batch_amount(now), 3);
+
+        assert_eq!(pocket.plan_catch_up(now), (1, 3));
+        assert_eq!(pocket.get_next_scheduled_execution_at(now), now + 3600);
+
+        pocket.catch_up_policy = CatchUpPolicy::Accumulate { max_batches: 3 };
+        assert_eq!(pocket.plan_catch_up(now), (3, 1));
+
+        pocket.catch_up_policy = CatchUpPolicy::Accumulate { max_batches: 10 };
+        assert_eq!(pocket.plan_catch_up(now), (4, 0));
+
+        pocket.catch_up_policy = CatchUpPolicy::OneByOne;
+        assert_eq!(pocket.plan_catch_up(now), (1, 0));
+        assert_eq!(pocket.get_next_scheduled_execution_at(now), start_at + 2 * 3600);
+
+        // the anchored schedule runs the last missed execution on the grid
+        pocket.schedule_mode = ScheduleMode::Anchored;
+        pocket.next_scheduled_execution_at = start_at + 4 * 3600;
+        assert_eq!(pocket.get_missed_batch_amount(now), 0);
+        assert_eq!(pocket.get_next_scheduled_execution_at(now), start_at + 5 * 3600);
+
+        // the months are counted one by one
+        pocket.frequency = Some(DateDuration::Monthly { day: 1, hour: 9, minute: 0 });
+        pocket.next_scheduled_execution_at = start_at;
+        assert_eq!(pocket.get_missed_batch_amount(start_at + 100 * 86400), 3);
+
+        assert!(!CatchUpPolicy::Accumulate { max_batches: 0 }.is_valid());
+    }
+
+    #[test]
+    fn should_roll_next_execution_to_execution_window() {
+        // Monday, 2024-01-01 09:00:00 UTC
+        let start_at = 1_704_099_600;
+
+        let mut pocket = Pocket {
+            start_at,
+            frequency: Some(DateDuration::Hours { hours: 4 }),
+            ..Pocket::default()
+        };
+
+        assert!(pocket.is_in_execution_window(start_at + 12 * 3600));
+        assert_eq!(pocket.get_next_scheduled_execution_at(start_at + 12 * 3600), start_at + 16 * 3600);
+
+        // from 08:00 to 18:00 on Mondays, and from 10:00 to 12:00 on Tuesdays
+        pocket.execution_windows = vec![
+            ExecutionWindow { weekdays: 0b01, start_minute: 8 * 60, end_minute: 18 * 60 },
+            ExecutionWindow { weekdays: 0b10, start_minute: 10 * 60, end_minute: 12 * 60 },
+        ];
+
+        assert!(pocket.is_in_execution_window(start_at));
+        assert!(!pocket.is_in_execution_window(start_at + 10 * 3600));
+
+        // the run at 13:00 is kept, the run at 19:00 is rolled forward to Tuesday 10:00
+        assert_eq!(pocket.get_next_scheduled_execution_at(start_at), start_at + 4 * 3600);
+        assert_eq!(pocket.get_next_scheduled_execution_at(start_at + 6 * 3600), start_at + 25 * 3600);
+    }
+
+    #[test]
+    fn should_start_waiting_pocket_on_price_condition() {
+        // Monday, 2024-01-01 09:00:00 UTC
+        let start_at = 1_704_099_600;
+
+        let mut pocket = Pocket {
+            start_at,
+            status: PocketStatus::Waiting,
+            start_condition: Some(PriceCondition::Gte { value: 1_000 }),
+            execution_windows: vec![ExecutionWindow { weekdays: 0b1, start_minute: 10 * 60, end_minute: 12 * 60 }],
+            ..Pocket::default()
+        };
+
+        assert!(!pocket.is_able_to_start(999));
+        assert!(pocket.is_able_to_start(1_000));
+
+        // the first batch waits for the execution window
+        pocket.start(start_at);
+        assert_eq!(pocket.status, PocketStatus::Active);
+        assert_eq!(pocket.next_scheduled_execution_at, start_at + 3600);
+        assert!(!pocket.is_able_to_start(1_000));
+
+        // the started pocket doesn't wait again once resumed
+        assert_eq!(pocket.get_restart_status(), PocketStatus::Active);
+
+        assert!(PriceCondition::is_fulfilled(&PriceCondition::Bw { from_value: 1, to_value: 3 }, 3));
+        assert!(!PriceCondition::is_fulfilled(&PriceCondition::Nbw { from_value: 1, to_value: 3 }, 2));
+        assert!(PriceCondition::is_fulfilled(&PriceCondition::Neq { value: 1 }, 2));
+    }
+
+    #[test]
+    fn should_reschedule_edited_pocket() {
+        // Monday, 2024-01-01 09:00:00 UTC
+        let now = 1_704_099_600;
+
+        let mut pocket = Pocket {
+            start_at: now - 3600 * 24,
+            frequency: Some(DateDuration::Hours { hours: 1 }),
+            next_scheduled_execution_at: now - 3600 * 12,
+            ..Pocket::default()
+        };
+
+        // the stale schedule is not caught up
+        pocket.frequency = Some(DateDuration::Hours { hours: 6 });
+        pocket.reschedule(now);
+        assert_eq!(pocket.next_scheduled_execution_at, now + 3600 * 6);
+
+        // the pocket which didn't start yet runs at its new start time
+        pocket.start_at = now + 3600 * 24;
+        pocket.reschedule(now);
+        assert_eq!(pocket.next_scheduled_execution_at, now + 3600 * 24);
+    }
+}
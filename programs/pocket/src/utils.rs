@@ -0,0 +1,84 @@
+//! Helpers to grow and upgrade program owned accounts in place.
+use crate::*;
+
+/// Grows the account to the new size, the payer funds the rent exemption of the extra space.
+/// The reserved lamports are held by the account on top of its rent, e.g. the keeper tip of a pocket, and are never used to fund the rent.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_size: usize,
+    reserved_lamports: u64,
+) -> Result<()> {
+    let size = new_size.max(account.data_len());
+
+    let required_lamports = Rent::get().unwrap()
+        .minimum_balance(size)
+        .saturating_add(reserved_lamports)
+        .saturating_sub(account.lamports());
+
+    if required_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports,
+        ).unwrap();
+    }
+
+    if account.data_len() >= new_size {
+        return Ok(());
+    }
+
+    // the extra space is zero-initialized so that the appended fields read as their defaults
+    account.realloc(new_size, true).unwrap();
+
+    Ok(())
+}
+
+/// Grows the account so that its current state fits, the payer funds the extra rent.
+pub fn realloc_account_to_fit<'info, T>(
+    account: &Account<'info, T>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()>
+    where T: AccountSerialize + AccountDeserialize + Owner + Clone
+{
+    let mut data: Vec<u8> = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+
+    realloc_account(&account.to_account_info(), payer, system_program, data.len(), 0)
+}
+
+/// Reads the account state, the legacy layouts are padded with zeros.
+pub fn load_account_state<T: AccountDeserialize>(account: &AccountInfo) -> Result<T> {
+    let data = account.try_borrow_data().unwrap();
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Reads the account state of an older layout, the account is padded with zeros when it is too short for the current layout.
+pub fn load_legacy_account_state<'info, T: AccountDeserialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    padding: usize,
+) -> Result<T> {
+    let state = load_account_state::<T>(account);
+
+    if state.is_ok() {
+        return state;
+    }
+
+    realloc_account(account, payer, system_program, account.data_len() + padding, 0).unwrap();
+    load_account_state(account)
+}
+
+/// Writes the account state back, including the discriminator.
+pub fn save_account_state<T: AccountSerialize>(account: &AccountInfo, state: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data().unwrap();
+    state.try_serialize(&mut &mut data[..])
+}
@@ -1,9 +1,9 @@
 import * as anchor from "@project-serum/anchor";
-import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { BorshCoder, EventParser } from "@project-serum/anchor";
 
-import { getFixtures } from "./test.helper";
+import { DEX_PROGRAM_ID, getFixtures } from "./test.helper";
 
 describe("assets", async () => {
   let fixtures: Awaited<ReturnType<typeof getFixtures>>;
@@ -23,6 +23,9 @@ describe("assets", async () => {
       owner,
       baseMintVaultAccount,
       targetMintVaultAccount,
+      pocketRegistry,
+      marketAccount,
+      ownerPocketIndex,
     } = fixtures;
 
     const pocketData = {
@@ -35,8 +38,10 @@ describe("assets", async () => {
       startAt: new anchor.BN(new Date().getTime().toString()),
       batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
       name: "pocket name",
-      frequency: { hours: new anchor.BN(1) },
-      marketKey: Keypair.generate().publicKey,
+      frequency: { hours: { hours: new anchor.BN(1) } },
+      marketKey: marketAccount,
+      keeperTipAmount: new anchor.BN(0),
+      keeperTipDeposit: new anchor.BN(0),
     };
 
     const inx = [
@@ -47,6 +52,7 @@ describe("assets", async () => {
           pocketTokenVault: baseMintVaultAccount,
           signer: owner.publicKey,
           pocket: pocketAccount,
+          pocketRegistry,
         })
         .instruction(),
       await program.methods
@@ -56,6 +62,7 @@ describe("assets", async () => {
           pocketTokenVault: targetMintVaultAccount,
           signer: owner.publicKey,
           pocket: pocketAccount,
+          pocketRegistry,
         })
         .instruction(),
     ];
@@ -65,6 +72,9 @@ describe("assets", async () => {
       .accounts({
         pocket: pocketAccount,
         signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
       })
       .signers([owner])
       .postInstructions(inx)
@@ -78,12 +88,14 @@ describe("assets", async () => {
       provider,
       program,
       pocketAccount,
+      pocketRegistry,
       owner,
       baseMintAccount,
       baseMintVaultAccount,
       targetMintVaultAccount,
       ownerBaseTokenAccount,
-      ownerTargetTokenAccount
+      ownerTargetTokenAccount,
+      ownerPocketIndex,
     } = fixtures;
 
     const txId = await program.methods
@@ -94,6 +106,8 @@ describe("assets", async () => {
       .accounts({
         signer: owner.publicKey,
         pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
         pocketBaseTokenVault: baseMintVaultAccount,
         pocketQuoteTokenVault: targetMintVaultAccount,
         signerBaseTokenAccount: ownerBaseTokenAccount.address,
@@ -136,10 +150,137 @@ describe("assets", async () => {
 
   });
 
+  it("[deposit] should: fail to deposit from a token account of another mint", async () => {
+    const {
+      program,
+      pocketAccount,
+      pocketRegistry,
+      owner,
+      baseMintVaultAccount,
+      targetMintVaultAccount,
+      ownerBaseTokenAccount,
+      ownerTargetTokenAccount,
+      ownerPocketIndex,
+    } = fixtures;
+
+    await program.methods
+      .deposit({
+        depositAmount: new anchor.BN(LAMPORTS_PER_SOL),
+        mode: {base: {}}
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        pocketBaseTokenVault: baseMintVaultAccount,
+        pocketQuoteTokenVault: targetMintVaultAccount,
+        // swapped token accounts
+        signerBaseTokenAccount: ownerTargetTokenAccount.address,
+        signerQuoteTokenAccount: ownerBaseTokenAccount.address,
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[withdraw] should: owner can partially withdraw from the running pocket", async () => {
+    const {
+      program,
+      pocketAccount,
+      pocketRegistry,
+      owner,
+      baseMintAccount,
+      baseMintVaultAccount,
+      targetMintVaultAccount,
+      ownerBaseTokenAccount,
+      ownerTargetTokenAccount,
+      ownerPocketIndex,
+    } = fixtures;
+
+    await program.methods
+      .withdraw({
+        mode: {
+          partial: {
+            mintAddress: baseMintAccount,
+            amount: new anchor.BN(LAMPORTS_PER_SOL / 2),
+          },
+        },
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketBaseTokenVault: baseMintVaultAccount,
+        pocketQuoteTokenVault: targetMintVaultAccount,
+        signerBaseTokenAccount: ownerBaseTokenAccount.address,
+        signerQuoteTokenAccount: ownerTargetTokenAccount.address
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .catch((e) => console.log(e));
+
+    const pocketState = await program.account.pocket.fetch(pocketAccount);
+
+    expect(!!pocketState.status.active).to.be.true;
+    expect(pocketState.baseTokenBalance.eq(new anchor.BN(LAMPORTS_PER_SOL * 1.5))).to.be.true;
+    expect(pocketState.totalBaseDepositAmount.eq(new anchor.BN(LAMPORTS_PER_SOL * 1.5))).to.be.true;
+
+    // can't withdraw more than the balance
+    await program.methods
+      .withdraw({
+        mode: {
+          partial: {
+            mintAddress: baseMintAccount,
+            amount: new anchor.BN(LAMPORTS_PER_SOL * 2),
+          },
+        },
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketBaseTokenVault: baseMintVaultAccount,
+        pocketQuoteTokenVault: targetMintVaultAccount,
+        signerBaseTokenAccount: ownerBaseTokenAccount.address,
+        signerQuoteTokenAccount: ownerTargetTokenAccount.address
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // deposit the withdrawn amount back
+    await program.methods
+      .deposit({
+        depositAmount: new anchor.BN(LAMPORTS_PER_SOL / 2),
+        mode: {base: {}}
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        pocketBaseTokenVault: baseMintVaultAccount,
+        pocketQuoteTokenVault: targetMintVaultAccount,
+        signerBaseTokenAccount: ownerBaseTokenAccount.address,
+        signerQuoteTokenAccount: ownerTargetTokenAccount.address,
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .catch((e) => console.log(e));
+  });
+
   it("[withdraw] should: owner can withdraw assets from pocket successfully", async () => {
     const {
       program,
       pocketAccount,
+      pocketRegistry,
       owner,
       baseMintAccount,
       targetMintAccount,
@@ -147,14 +288,18 @@ describe("assets", async () => {
       targetMintVaultAccount,
       ownerBaseTokenAccount,
       ownerTargetTokenAccount,
-      provider
+      provider,
+      ownerPocketIndex,
     } = fixtures;
 
     const txId = await program.methods
-      .withdraw()
+      .withdraw({
+        mode: { full: {} },
+      })
       .accounts({
         signer: owner.publicKey,
         pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
         pocketBaseTokenVault: baseMintVaultAccount,
         pocketQuoteTokenVault: targetMintVaultAccount,
         signerBaseTokenAccount: ownerBaseTokenAccount.address,
@@ -168,6 +313,8 @@ describe("assets", async () => {
           .accounts({
             signer: owner.publicKey,
             pocket: pocketAccount,
+            pocketOwnerIndex: ownerPocketIndex,
+            pocketRegistry,
           })
           .instruction()
       ])
@@ -224,13 +371,15 @@ describe("assets", async () => {
       owner,
       baseMintVaultAccount,
       targetMintVaultAccount,
-      provider
+      provider,
+      ownerPocketIndex,
     } = fixtures;
 
     const beforeClosedBalance = await provider.connection.getBalance(owner.publicKey);
 
     await program.methods.closePocketAccounts().accounts({
       signer: owner.publicKey,
+      pocketOwnerIndex: ownerPocketIndex,
       pocket: pocketAccount,
       pocketBaseTokenVault: baseMintVaultAccount,
       pocketQuoteTokenVault: targetMintVaultAccount
@@ -246,4 +395,120 @@ describe("assets", async () => {
      */
     expect(afterClosedBalance).gt(beforeClosedBalance);
   });
+
+  it("[open_pocket] should: owner can open and tear down a pocket in one instruction", async () => {
+    const {
+      program,
+      provider,
+      owner,
+      pocketRegistry,
+      baseMintAccount,
+      targetMintAccount,
+      marketAccount,
+      ownerBaseTokenAccount,
+      ownerTargetTokenAccount,
+      ownerPocketIndex,
+    } = fixtures;
+
+    const pocketId = Keypair.generate().publicKey.toString().slice(0, 24);
+
+    const [pocket] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_SEED"),
+        owner.publicKey.toBytes(),
+        anchor.utils.bytes.utf8.encode(pocketId)
+      ],
+      program.programId
+    );
+
+    const [pocketBaseTokenVault, pocketQuoteTokenVault] = [baseMintAccount, targetMintAccount].map(
+      (mintAccount) => PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("SEED::POCKET::TOKEN_VAULT_SEED"),
+          pocket.toBytes(),
+          mintAccount.toBytes()
+        ],
+        program.programId
+      )[0]
+    );
+
+    const [openOrders] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::OPEN_ORDERS_SEED"),
+        pocket.toBytes(),
+        marketAccount.toBytes()
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .openPocket({
+        pocket: {
+          id: pocketId,
+          side: {buy: {}},
+          quoteTokenAddress: targetMintAccount,
+          baseTokenAddress: baseMintAccount,
+          stopConditions: [],
+          buyCondition: null,
+          startAt: new anchor.BN(new Date().getTime().toString()),
+          batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
+          name: "pocket name",
+          frequency: { hours: { hours: new anchor.BN(1) } },
+          marketKey: marketAccount,
+          keeperTipAmount: new anchor.BN(0),
+          keeperTipDeposit: new anchor.BN(0),
+        },
+        baseDepositAmount: new anchor.BN(LAMPORTS_PER_SOL),
+        quoteDepositAmount: new anchor.BN(0),
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocket,
+        pocketRegistry,
+        baseMintAccount,
+        quoteMintAccount: targetMintAccount,
+        pocketBaseTokenVault,
+        pocketQuoteTokenVault,
+        signerBaseTokenAccount: ownerBaseTokenAccount.address,
+        // the quote token account is not loaded since no quote token is deposited
+        signerQuoteTokenAccount: owner.publicKey,
+        openOrders,
+        marketKey: marketAccount,
+        dexProgram: DEX_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .catch((e) => console.log(e));
+
+    const pocketState = await program.account.pocket.fetch(pocket);
+    expect(!!pocketState.status.active).to.be.true;
+    expect(pocketState.baseTokenBalance.eq(new anchor.BN(LAMPORTS_PER_SOL))).to.be.true;
+
+    const openOrdersInfo = await provider.connection.getAccountInfo(openOrders);
+    expect(openOrdersInfo.owner.equals(DEX_PROGRAM_ID)).to.be.true;
+
+    await program.methods
+      .teardownPocket()
+      .accounts({
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocket,
+        signerBaseTokenAccount: ownerBaseTokenAccount.address,
+        signerQuoteTokenAccount: ownerTargetTokenAccount.address,
+        pocketBaseTokenVault,
+        pocketQuoteTokenVault,
+        openOrders,
+        marketKey: marketAccount,
+        dexProgram: DEX_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .catch((e) => console.log(e));
+
+    // every account of the pocket is closed
+    for (const account of [pocket, pocketBaseTokenVault, pocketQuoteTokenVault, openOrders]) {
+      expect(await provider.connection.getAccountInfo(account)).to.be.null;
+    }
+  });
 });
@@ -1,5 +1,5 @@
 import * as anchor from "@project-serum/anchor";
-import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, Transaction } from "@solana/web3.js";
 import { expect } from "chai";
 import { getAccount } from "@solana/spl-token";
 import { getFixtures } from "./test.helper";
@@ -20,7 +20,10 @@ describe("pocket", async () => {
       targetMintAccount,
       baseMintAccount,
       pocketAccount,
+      pocketRegistry,
+      marketAccount,
       owner,
+      ownerPocketIndex,
     } = fixtures;
 
     const pocketData = {
@@ -33,8 +36,11 @@ describe("pocket", async () => {
       startAt: new anchor.BN(new Date().getTime().toString()),
       batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
       name: "pocket name",
-      frequency: { hours: new anchor.BN(1) },
-      marketKey: Keypair.generate().publicKey,
+      frequency: { hours: { hours: new anchor.BN(1) } },
+      scheduleMode: { anchored: {} },
+      marketKey: marketAccount,
+      keeperTipAmount: new anchor.BN(0),
+      keeperTipDeposit: new anchor.BN(0),
     };
 
     const txId = await program.methods
@@ -42,6 +48,9 @@ describe("pocket", async () => {
       .accounts({
         pocket: pocketAccount,
         signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
       })
       .signers([owner])
       .rpc({ commitment: "confirmed" })
@@ -64,7 +73,8 @@ describe("pocket", async () => {
     // @ts-ignore
     expect(pocket.stopConditions.length === 0).to.be.true;
     expect(!!pocket.buyCondition).to.be.false;
-    expect(pocket.frequency.hours.eq(pocketData.frequency.hours)).to.be.true;
+    expect(pocket.frequency.hours.hours.eq(pocketData.frequency.hours.hours)).to.be.true;
+    expect(!!pocket.scheduleMode.anchored).to.be.true;
     expect(pocket.batchVolume.eq(pocketData.batchVolume)).to.be.true;
     expect(pocket.marketKey.equals(pocketData.marketKey)).to.be.true;
 
@@ -92,6 +102,342 @@ describe("pocket", async () => {
     ).equals(true);
   });
 
+  it("[create_pocket] should: fail to create pocket with a market of another token pair", async () => {
+    const {
+      program,
+      targetMintAccount,
+      baseMintAccount,
+      pocketRegistry,
+      marketAccount,
+      owner,
+      ownerPocketIndex,
+    } = fixtures;
+
+    const pocketId = Keypair.generate().publicKey.toString().slice(0, 24);
+    const [pocketAccount] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_SEED"),
+        owner.publicKey.toBytes(),
+        anchor.utils.bytes.utf8.encode(pocketId)
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createPocket({
+        id: pocketId,
+        side: {buy: {}},
+        // reversed token pair
+        quoteTokenAddress: baseMintAccount,
+        baseTokenAddress: targetMintAccount,
+        stopConditions: [],
+        buyCondition: null,
+        startAt: new anchor.BN(new Date().getTime().toString()),
+        batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
+        name: "pocket name",
+        frequency: { hours: { hours: new anchor.BN(1) } },
+        marketKey: marketAccount,
+        keeperTipAmount: new anchor.BN(0),
+        keeperTipDeposit: new anchor.BN(0),
+      })
+      .accounts({
+        pocket: pocketAccount,
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[create_pocket] should: fail to create pocket with a name exceeding the maximum length", async () => {
+    const {
+      program,
+      targetMintAccount,
+      baseMintAccount,
+      pocketRegistry,
+      marketAccount,
+      owner,
+      ownerPocketIndex,
+    } = fixtures;
+
+    const pocketId = Keypair.generate().publicKey.toString().slice(0, 24);
+    const [pocketAccount] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_SEED"),
+        owner.publicKey.toBytes(),
+        anchor.utils.bytes.utf8.encode(pocketId)
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createPocket({
+        id: pocketId,
+        side: {buy: {}},
+        quoteTokenAddress: targetMintAccount,
+        baseTokenAddress: baseMintAccount,
+        stopConditions: [],
+        buyCondition: null,
+        startAt: new anchor.BN(new Date().getTime().toString()),
+        batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
+        name: "a".repeat(65),
+        frequency: { hours: { hours: new anchor.BN(1) } },
+        marketKey: marketAccount,
+        keeperTipAmount: new anchor.BN(0),
+        keeperTipDeposit: new anchor.BN(0),
+      })
+      .accounts({
+        pocket: pocketAccount,
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[create_pocket] should: fail to create pocket with an id of invalid characters", async () => {
+    const {
+      program,
+      targetMintAccount,
+      baseMintAccount,
+      pocketRegistry,
+      marketAccount,
+      owner,
+      ownerPocketIndex,
+    } = fixtures;
+
+    const pocketId = "pocket id!";
+    const [pocketAccount] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_SEED"),
+        owner.publicKey.toBytes(),
+        anchor.utils.bytes.utf8.encode(pocketId)
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createPocket({
+        id: pocketId,
+        side: {buy: {}},
+        quoteTokenAddress: targetMintAccount,
+        baseTokenAddress: baseMintAccount,
+        stopConditions: [],
+        buyCondition: null,
+        startAt: new anchor.BN(new Date().getTime().toString()),
+        batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
+        name: "pocket name",
+        frequency: { hours: { hours: new anchor.BN(1) } },
+        marketKey: marketAccount,
+        keeperTipAmount: new anchor.BN(0),
+        keeperTipDeposit: new anchor.BN(0),
+      })
+      .accounts({
+        pocket: pocketAccount,
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[create_nonce_pocket] should: owner index lists the pockets and addresses them by nonce", async () => {
+    const {
+      program,
+      targetMintAccount,
+      baseMintAccount,
+      pocketAccount,
+      pocketRegistry,
+      marketAccount,
+      owner,
+      ownerPocketIndex,
+    } = fixtures;
+
+    let index = await program.account.pocketOwnerIndex.fetch(ownerPocketIndex);
+    expect(index.owner.equals(owner.publicKey)).to.be.true;
+    expect(index.pockets.length).eq(1);
+    expect(index.pockets[0].pocketAddress.equals(pocketAccount)).to.be.true;
+    expect(!!index.pockets[0].status.active).to.be.true;
+
+    const [noncePocketAccount] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_NONCE_SEED"),
+        owner.publicKey.toBytes(),
+        index.nextNonce.toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createNoncePocket({
+        id: "label",
+        side: {buy: {}},
+        quoteTokenAddress: targetMintAccount,
+        baseTokenAddress: baseMintAccount,
+        stopConditions: [],
+        buyCondition: null,
+        startAt: new anchor.BN(new Date().getTime().toString()),
+        batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
+        name: "nonce pocket",
+        frequency: { hours: { hours: new anchor.BN(1) } },
+        marketKey: marketAccount,
+        keeperTipAmount: new anchor.BN(0),
+        keeperTipDeposit: new anchor.BN(0),
+      })
+      .accounts({
+        pocket: noncePocketAccount,
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    const pocket = await program.account.pocket.fetch(noncePocketAccount);
+    expect(pocket.owner.equals(owner.publicKey)).to.be.true;
+    expect(!!pocket.addressSeed.ownerNonce).to.be.true;
+
+    index = await program.account.pocketOwnerIndex.fetch(ownerPocketIndex);
+    expect(index.nextNonce.eq(new anchor.BN(1))).to.be.true;
+    expect(index.pockets.length).eq(2);
+    expect(index.pockets[1].pocketAddress.equals(noncePocketAccount)).to.be.true;
+  });
+
+  it("[check_start_condition] should: pocket waits until the market price reaches the start condition", async () => {
+    const {
+      program,
+      targetMintAccount,
+      baseMintAccount,
+      pocketRegistry,
+      marketAccount,
+      marketBids,
+      marketAsks,
+      operator,
+      owner,
+      ownerPocketIndex,
+    } = fixtures;
+
+    const pocketId = "waiting-pocket";
+    const [pocketAccount] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_SEED"),
+        owner.publicKey.toBytes(),
+        anchor.utils.bytes.utf8.encode(pocketId)
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createPocket({
+        id: pocketId,
+        side: {buy: {}},
+        quoteTokenAddress: targetMintAccount,
+        baseTokenAddress: baseMintAccount,
+        stopConditions: [],
+        buyCondition: null,
+        startCondition: { gte: { value: new anchor.BN(LAMPORTS_PER_SOL) } },
+        startAt: new anchor.BN(new Date().getTime().toString()),
+        batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 10).toString()),
+        name: "waiting pocket",
+        frequency: { hours: { hours: new anchor.BN(1) } },
+        marketKey: marketAccount,
+        keeperTipAmount: new anchor.BN(0),
+        keeperTipDeposit: new anchor.BN(0),
+      })
+      .accounts({
+        pocket: pocketAccount,
+        signer: owner.publicKey,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(!!pocket.status.waiting).to.be.true;
+
+    // the pocket keeps waiting until the start condition is reached
+    await program.methods
+      .checkStartCondition()
+      .accounts({
+        signer: operator.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+        marketKey: marketAccount,
+        marketBids,
+        marketAsks,
+      })
+      .signers([operator])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(!!pocket.status.waiting).to.be.true;
+
+    // the waiting pocket can be paused and edited, it waits again once resumed
+    await program.methods
+      .updatePocket({
+        status: { paused: {} },
+        startCondition: { gte: { value: new anchor.BN(LAMPORTS_PER_SOL * 2) } },
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(!!pocket.status.paused).to.be.true;
+    expect(pocket.startCondition.gte.value.eq(new anchor.BN(LAMPORTS_PER_SOL * 2))).to.be.true;
+
+    await program.methods
+      .updatePocket({
+        status: { active: {} },
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(!!pocket.status.waiting).to.be.true;
+  });
+
   it("[create_token_vault] should: pocket owner can create token vault successfully", async () => {
     const {
       program,
@@ -101,6 +447,7 @@ describe("pocket", async () => {
       pocketAccount,
       targetMintVaultAccount,
       baseMintVaultAccount,
+      pocketRegistry,
       owner,
     } = fixtures;
 
@@ -117,6 +464,7 @@ describe("pocket", async () => {
           pocketTokenVault: baseMintVaultAccount,
           signer: owner.publicKey,
           pocket: pocketAccount,
+          pocketRegistry,
         })
         .instruction(),
       await program.methods
@@ -126,6 +474,7 @@ describe("pocket", async () => {
           pocketTokenVault: targetMintVaultAccount,
           signer: owner.publicKey,
           pocket: pocketAccount,
+          pocketRegistry,
         })
         .instruction(),
     ];
@@ -176,7 +525,7 @@ describe("pocket", async () => {
   });
 
   it("[pause_pocket] should: owner should pause pocket successfully", async () => {
-    const { provider, program, pocketAccount, owner } = fixtures;
+    const { provider, program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
 
     const txId = await program.methods
       .updatePocket({
@@ -185,6 +534,8 @@ describe("pocket", async () => {
       .accounts({
         signer: owner.publicKey,
         pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
       })
       .signers([owner])
       .rpc({commitment: "confirmed"})
@@ -194,6 +545,10 @@ describe("pocket", async () => {
 
     expect(!!pocket.status.paused).to.be.true;
 
+    // the status is refreshed in the owner index
+    const index = await program.account.pocketOwnerIndex.fetch(ownerPocketIndex);
+    expect(!!index.pockets.find((x) => x.pocketAddress.equals(pocketAccount)).status.paused).to.be.true;
+
     // expect log
     const transaction = await provider.connection.getParsedTransaction(txId as string, {
       commitment: "confirmed",
@@ -218,7 +573,7 @@ describe("pocket", async () => {
   });
 
   it("[pause_pocket] should: owner should not pause pocket that was already paused", async () => {
-    const { program, pocketAccount, owner } = fixtures;
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
 
     await program.methods
       .updatePocket({
@@ -227,6 +582,104 @@ describe("pocket", async () => {
       .accounts({
         signer: owner.publicKey,
         pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+      })
+      .signers([owner])
+      .rpc()
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[update_pocket] should: owner can edit the strategy of the paused pocket", async () => {
+    const { provider, program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
+
+    const txId = await program.methods
+      .updatePocket({
+        name: "edited pocket name",
+        batchVolume: new anchor.BN((LAMPORTS_PER_SOL * 5).toString()),
+        frequency: { hours: { hours: new anchor.BN(2) } },
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
+      })
+      .signers([owner])
+      .rpc({commitment: "confirmed"})
+      .catch((e) => console.log(e));
+
+    const pocket = await program.account.pocket.fetch(pocketAccount);
+
+    expect(pocket.name).eq("edited pocket name");
+    expect(pocket.batchVolume.eq(new anchor.BN((LAMPORTS_PER_SOL * 5).toString()))).to.be.true;
+    expect(pocket.frequency.hours.hours.eq(new anchor.BN(2))).to.be.true;
+    expect(!!pocket.status.paused).to.be.true;
+
+    // expect log
+    const transaction = await provider.connection.getParsedTransaction(txId as string, {
+      commitment: "confirmed",
+    });
+    const eventParser = new EventParser(
+      program.programId,
+      new BorshCoder(program.idl)
+    );
+    const [event] = eventParser.parseLogs(transaction.meta.logMessages);
+
+    expect(event.name).eq('PocketStrategyUpdated');
+    expect((event.data as any).before.name).eq("pocket name");
+    expect((event.data as any).after.name).eq("edited pocket name");
+  });
+
+  it("[update_pocket] should: owner can schedule the paused pocket on a weekday", async () => {
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
+
+    const updatePocket = (params: any) =>
+      program.methods
+        .updatePocket(params)
+        .accounts({
+          signer: owner.publicKey,
+          pocket: pocketAccount,
+          pocketOwnerIndex: ownerPocketIndex,
+          pocketRegistry,
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+
+    // every Monday at 09:00 UTC
+    await updatePocket({
+      frequency: { weekly: { weekday: 0, hour: 9, minute: 0 } },
+    }).catch((e) => console.log(e));
+
+    const pocket = await program.account.pocket.fetch(pocketAccount);
+
+    expect(pocket.frequency.weekly.weekday).eq(0);
+    expect(pocket.frequency.weekly.hour).eq(9);
+
+    await updatePocket({
+      frequency: { weekly: { weekday: 7, hour: 9, minute: 0 } },
+    })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[update_pocket] should: owner fails to edit the strategy with invalid params", async () => {
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
+
+    await program.methods
+      .updatePocket({
+        batchVolume: new anchor.BN(0),
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
       })
       .signers([owner])
       .rpc()
@@ -237,7 +690,7 @@ describe("pocket", async () => {
   });
 
   it("[restart_pocket] should: owner can restart the paused pocket successfully", async () => {
-    const { program, pocketAccount, owner } = fixtures;
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
 
     await program.methods
       .updatePocket({
@@ -246,6 +699,8 @@ describe("pocket", async () => {
       .accounts({
         signer: owner.publicKey,
         pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
       })
       .signers([owner])
       .rpc()
@@ -256,8 +711,201 @@ describe("pocket", async () => {
     expect(!!pocket.status.active).to.be.true;
   });
 
+  it("[set_pocket_delegate] should: delegate can only use the granted permissions", async () => {
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
+    const delegate = Keypair.generate();
+
+    await program.methods
+      .setPocketDelegate({
+        delegate: delegate.publicKey,
+        permissions: { canUpdateStatus: true, canEdit: false, canDeposit: false },
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(pocket.delegates.length).eq(1);
+
+    const updatePocket = (params: any) =>
+      program.methods
+        .updatePocket(params)
+        .accounts({
+          signer: delegate.publicKey,
+          pocket: pocketAccount,
+          pocketOwnerIndex: ownerPocketIndex,
+          pocketRegistry,
+        })
+        .signers([delegate])
+        .rpc({ commitment: "confirmed" });
+
+    await updatePocket({ status: { paused: {} } }).catch((e) => console.log(e));
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(!!pocket.status.paused).to.be.true;
+
+    // the delegate can neither edit nor close the pocket
+    await updatePocket({ batchVolume: new anchor.BN(1) })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await updatePocket({ status: { closed: {} } })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await updatePocket({ status: { active: {} } }).catch((e) => console.log(e));
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(!!pocket.status.active).to.be.true;
+
+    await program.methods
+      .removePocketDelegate({
+        delegate: delegate.publicKey,
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(pocket.delegates.length).eq(0);
+  });
+
+  it("[transfer_pocket_ownership] should: ownership is only transferred once the new owner accepts", async () => {
+    const { provider, program, pocketAccount, owner, ownerPocketIndex } = fixtures;
+    const newOwner = Keypair.generate();
+    await provider.connection.requestAirdrop(newOwner.publicKey, LAMPORTS_PER_SOL);
+
+    const [newOwnerPocketIndex] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_OWNER_INDEX_SEED"),
+        newOwner.publicKey.toBytes()
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .transferPocketOwnership({
+        newOwner: newOwner.publicKey,
+        requireAcceptance: true,
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        newPocketOwnerIndex: newOwnerPocketIndex,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(pocket.owner.equals(owner.publicKey)).to.be.true;
+    expect(pocket.pendingOwner.equals(newOwner.publicKey)).to.be.true;
+
+    await program.methods
+      .acceptPocketOwnership()
+      .accounts({
+        signer: newOwner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        newPocketOwnerIndex: newOwnerPocketIndex,
+      })
+      .signers([newOwner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(pocket.owner.equals(newOwner.publicKey)).to.be.true;
+    expect(pocket.pendingOwner).to.be.null;
+
+    // the pocket is moved into the index of the new owner
+    let index = await program.account.pocketOwnerIndex.fetch(ownerPocketIndex);
+    expect(index.pockets.some((x) => x.pocketAddress.equals(pocketAccount))).to.be.false;
+    index = await program.account.pocketOwnerIndex.fetch(newOwnerPocketIndex);
+    expect(index.pockets.some((x) => x.pocketAddress.equals(pocketAccount))).to.be.true;
+
+    // transfer back without acceptance
+    await program.methods
+      .transferPocketOwnership({
+        newOwner: owner.publicKey,
+        requireAcceptance: false,
+      })
+      .accounts({
+        signer: newOwner.publicKey,
+        pocket: pocketAccount,
+        pocketOwnerIndex: newOwnerPocketIndex,
+        newPocketOwnerIndex: ownerPocketIndex,
+      })
+      .signers([newOwner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(pocket.owner.equals(owner.publicKey)).to.be.true;
+
+    index = await program.account.pocketOwnerIndex.fetch(ownerPocketIndex);
+    expect(index.pockets.some((x) => x.pocketAddress.equals(pocketAccount))).to.be.true;
+
+    // the owner can cancel a pending transfer by proposing itself
+    for (const proposedOwner of [newOwner.publicKey, owner.publicKey]) {
+      await program.methods
+        .transferPocketOwnership({
+          newOwner: proposedOwner,
+          requireAcceptance: true,
+        })
+        .accounts({
+          signer: owner.publicKey,
+          pocket: pocketAccount,
+          pocketOwnerIndex: ownerPocketIndex,
+          newPocketOwnerIndex: proposedOwner.equals(owner.publicKey) ? ownerPocketIndex : newOwnerPocketIndex,
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" })
+        .catch((e) => console.log(e));
+    }
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+    expect(pocket.owner.equals(owner.publicKey)).to.be.true;
+    expect(pocket.pendingOwner).to.be.null;
+  });
+
+  it("[top_up_keeper_tip] should: owner can reserve lamports for operator tips", async () => {
+    const { provider, program, pocketAccount, pocketRegistry, owner } = fixtures;
+
+    const beforeBalance = await provider.connection.getBalance(pocketAccount);
+
+    await program.methods
+      .topUpKeeperTip({
+        amount: new anchor.BN(LAMPORTS_PER_SOL / 10),
+      })
+      .accounts({
+        signer: owner.publicKey,
+        pocket: pocketAccount,
+        pocketRegistry,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    const pocket = await program.account.pocket.fetch(pocketAccount);
+    const afterBalance = await provider.connection.getBalance(pocketAccount);
+
+    expect(pocket.keeperTipBalance.eq(new anchor.BN(LAMPORTS_PER_SOL / 10))).to.be.true;
+    expect(afterBalance - beforeBalance).eq(LAMPORTS_PER_SOL / 10);
+  });
+
   it("[close_pocket] should: owner can close pocket successfully", async () => {
-    const { program, pocketAccount, owner } = fixtures;
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
 
     await program.methods
       .updatePocket({
@@ -266,6 +914,8 @@ describe("pocket", async () => {
       .accounts({
         signer: owner.publicKey,
         pocket: pocketAccount,
+        pocketOwnerIndex: ownerPocketIndex,
+        pocketRegistry,
       })
       .signers([owner])
       .rpc()
@@ -275,4 +925,58 @@ describe("pocket", async () => {
 
     expect(!!pocket.status.closed).to.be.true;
   });
+
+  it("[reopen_pocket] should: owner can reopen the closed pocket with new stop conditions", async () => {
+    const { program, pocketAccount, pocketRegistry, owner, ownerPocketIndex } = fixtures;
+
+    const updatePocket = (params: any) =>
+      program.methods
+        .updatePocket(params)
+        .accounts({
+          signer: owner.publicKey,
+          pocket: pocketAccount,
+          pocketOwnerIndex: ownerPocketIndex,
+          pocketRegistry,
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+
+    // the closed pocket can't be reopened without new stop conditions
+    await updatePocket({ status: { active: {} } })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // nor with an empty list of stop conditions
+    await updatePocket({ status: { active: {} }, stopConditions: [] })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    const endTime = Math.floor(new Date().getTime() / 1000) + 3600 * 24;
+
+    await updatePocket({
+      status: { active: {} },
+      stopConditions: [
+        { endTimeReach: { isPrimary: true, value: new anchor.BN(endTime) } },
+      ],
+      autoRestartOnDeposit: true,
+    }).catch((e) => console.log(e));
+
+    let pocket = await program.account.pocket.fetch(pocketAccount);
+
+    expect(!!pocket.status.active).to.be.true;
+    expect(!!pocket.closeReason.none).to.be.true;
+    expect(pocket.autoRestartOnDeposit).to.be.true;
+    expect(pocket.stopConditions.length).eq(1);
+
+    await updatePocket({ status: { closed: {} } }).catch((e) => console.log(e));
+
+    pocket = await program.account.pocket.fetch(pocketAccount);
+
+    expect(!!pocket.status.closed).to.be.true;
+    expect(!!pocket.closeReason.user).to.be.true;
+  });
 });
@@ -1,6 +1,12 @@
 import * as anchor from "@project-serum/anchor";
-import { Keypair, PublicKey, SendTransactionError } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SendTransactionError
+} from "@solana/web3.js";
 import { expect } from "chai";
+import { createMint, getAccount } from "@solana/spl-token";
 
 import { IDL } from "../target/types/pocket";
 
@@ -78,7 +84,7 @@ describe("pocket_registry", async () => {
       })
       .accounts({
         pocketRegistry,
-        owner: deployer.publicKey,
+        signer: deployer.publicKey,
       })
       .signers([deployer.payer])
       .rpc({ commitment: "confirmed" })
@@ -90,4 +96,657 @@ describe("pocket_registry", async () => {
     expect(pocketRegistryAccount.operators.length).eq(1);
     expect(pocketRegistryAccount.operators[0].equals(newOperator)).to.be.true;
   });
+
+  it("[add_mint_account] should: deployer can whitelist a mint account", async () => {
+    const mintAccount = await createMint(
+      provider.connection,
+      deployer.payer,
+      deployer.publicKey,
+      deployer.publicKey,
+      9
+    );
+
+    await program.methods
+      .addMintAccount()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+        mintAccount,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let state = await program.account.pocketPlatformRegistry.fetch(
+      pocketRegistry
+    );
+    // @ts-ignore
+    let mintInfo = state.allowedMintAccounts.find((info) =>
+      info.mintAccount.equals(mintAccount)
+    );
+    expect(mintInfo.isEnabled).to.be.true;
+
+    // re-adding the same mint account should fail
+    await program.methods
+      .addMintAccount()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+        mintAccount,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await program.methods
+      .disableMintAccount()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+        mintAccount,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    // @ts-ignore
+    mintInfo = state.allowedMintAccounts.find((info) =>
+      info.mintAccount.equals(mintAccount)
+    );
+    expect(mintInfo.isEnabled).to.be.false;
+
+    await program.methods
+      .removeMintAccount()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+        mintAccount,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(
+      // @ts-ignore
+      state.allowedMintAccounts.some((info) =>
+        info.mintAccount.equals(mintAccount)
+      )
+    ).to.be.false;
+  });
+
+  it("[add_mint_account] should: non-owner fails to whitelist a mint account", async () => {
+    const nonOwner = Keypair.generate();
+    const mintAccount = await createMint(
+      provider.connection,
+      deployer.payer,
+      deployer.publicKey,
+      deployer.publicKey,
+      9
+    );
+
+    await program.methods
+      .addMintAccount()
+      .accounts({
+        signer: nonOwner.publicKey,
+        pocketRegistry,
+        mintAccount,
+      })
+      .signers([nonOwner])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[update_platform_fee] should: deployer can update the platform fee", async () => {
+    await program.methods
+      .updatePlatformFee({
+        platformFeeBps: new anchor.BN(25),
+        keeperTipAmount: new anchor.BN(5000),
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    const state = await program.account.pocketPlatformRegistry.fetch(
+      pocketRegistry
+    );
+    expect(state.platformFeeBps.eq(new anchor.BN(25))).to.be.true;
+    expect(state.keeperTipAmount.eq(new anchor.BN(5000))).to.be.true;
+
+    // fee rate above the cap should fail
+    await program.methods
+      .updatePlatformFee({
+        platformFeeBps: new anchor.BN(10_001),
+        keeperTipAmount: new anchor.BN(0),
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[create_treasury_vault] should: deployer can create the treasury vault of a whitelisted mint", async () => {
+    const mintAccount = await createMint(
+      provider.connection,
+      deployer.payer,
+      deployer.publicKey,
+      deployer.publicKey,
+      9
+    );
+
+    const [platformTreasuryVault] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("SEED::POCKET::TREASURY_SEED"),
+        mintAccount.toBytes(),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createTreasuryVault()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+        mintAccount,
+        platformTreasuryVault,
+      })
+      .preInstructions([
+        await program.methods
+          .addMintAccount()
+          .accounts({
+            signer: deployer.publicKey,
+            pocketRegistry,
+            mintAccount,
+          })
+          .instruction(),
+      ])
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    const treasuryVault = await getAccount(
+      provider.connection,
+      platformTreasuryVault
+    );
+    expect(treasuryVault.owner.equals(pocketRegistry)).to.be.true;
+
+    const state = await program.account.pocketPlatformRegistry.fetch(
+      pocketRegistry
+    );
+    // @ts-ignore
+    const mintInfo = state.allowedMintAccounts.find((info) =>
+      info.mintAccount.equals(mintAccount)
+    );
+    expect(mintInfo.tokenAccount.equals(platformTreasuryVault)).to.be.true;
+  });
+
+  it("[transfer_registry_owner] should: ownership is only transferred once the pending owner accepts", async () => {
+    const newOwner = Keypair.generate();
+    await provider.connection.requestAirdrop(newOwner.publicKey, LAMPORTS_PER_SOL);
+
+    await program.methods
+      .proposeRegistryOwner({
+        newOwner: newOwner.publicKey,
+      })
+      .accounts({
+        owner: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.owner.equals(deployer.publicKey)).to.be.true;
+    expect(state.pendingOwner.equals(newOwner.publicKey)).to.be.true;
+
+    // only the pending owner can accept
+    await program.methods
+      .acceptRegistryOwner()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await program.methods
+      .acceptRegistryOwner()
+      .accounts({
+        signer: newOwner.publicKey,
+        pocketRegistry,
+      })
+      .signers([newOwner])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.owner.equals(newOwner.publicKey)).to.be.true;
+    expect(state.pendingOwner).to.be.null;
+
+    // hand the ownership back to the deployer
+    await program.methods
+      .proposeRegistryOwner({
+        newOwner: deployer.publicKey,
+      })
+      .accounts({
+        owner: newOwner.publicKey,
+        pocketRegistry,
+      })
+      .postInstructions([
+        await program.methods
+          .acceptRegistryOwner()
+          .accounts({
+            signer: deployer.publicKey,
+            pocketRegistry,
+          })
+          .instruction(),
+      ])
+      .signers([newOwner, deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.owner.equals(deployer.publicKey)).to.be.true;
+  });
+
+  it("[grant_role] should: deployer can grant and revoke platform roles", async () => {
+    const pauser = Keypair.generate().publicKey;
+
+    await program.methods
+      .grantRole({
+        role: { pauser: {} },
+        account: pauser,
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    // @ts-ignore
+    expect(state.pausers.some((key) => key.equals(pauser))).to.be.true;
+
+    // granting twice should fail
+    await program.methods
+      .grantRole({
+        role: { pauser: {} },
+        account: pauser,
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await program.methods
+      .revokeRole({
+        role: { pauser: {} },
+        account: pauser,
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    // @ts-ignore
+    expect(state.pausers.some((key) => key.equals(pauser))).to.be.false;
+  });
+
+  it("[grant_role] should: non-admin fails to grant roles", async () => {
+    const nonAdmin = Keypair.generate();
+
+    await program.methods
+      .grantRole({
+        role: { operator: {} },
+        account: nonAdmin.publicKey,
+      })
+      .accounts({
+        signer: nonAdmin.publicKey,
+        pocketRegistry,
+      })
+      .signers([nonAdmin])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[pause_platform] should: owner can pause and unpause the platform", async () => {
+    await program.methods
+      .pausePlatform()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.isPaused).to.be.true;
+
+    // pausing twice should fail
+    await program.methods
+      .pausePlatform()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await program.methods
+      .unpausePlatform()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.isPaused).to.be.false;
+  });
+
+  it("[pause_platform] should: non-pauser fails to pause the platform", async () => {
+    const nonPauser = Keypair.generate();
+
+    await program.methods
+      .pausePlatform()
+      .accounts({
+        signer: nonPauser.publicKey,
+        pocketRegistry,
+      })
+      .signers([nonPauser])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+  });
+
+  it("[timelock] should: administrative changes go through the timelock once enabled", async () => {
+    const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+    const newOperator = Keypair.generate().publicKey;
+
+    const queueChange = async (action: any) => {
+      await program.methods
+        .queueAdminChange({ action })
+        .accounts({
+          signer: deployer.publicKey,
+          pocketRegistry,
+        })
+        .signers([deployer.payer])
+        .rpc({ commitment: "confirmed" })
+        .catch((e) => console.log(e));
+
+      const state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+      return state.nextChangeId.subn(1);
+    };
+
+    const executeChange = (changeId: anchor.BN) =>
+      program.methods
+        .executeAdminChange({ changeId })
+        .accounts({
+          signer: deployer.publicKey,
+          pocketRegistry,
+        })
+        .signers([deployer.payer])
+        .rpc({ commitment: "confirmed" });
+
+    // enable the timelock, the change is executable right away since there was no delay
+    let changeId = await queueChange({ updateTimelockDelay: { timelockDelay: new anchor.BN(2) } });
+    await executeChange(changeId).catch((e) => console.log(e));
+
+    let state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.timelockDelay.eq(new anchor.BN(2))).to.be.true;
+
+    // direct updates are rejected
+    await program.methods
+      .updatePocketRegistry({
+        operators: [newOperator],
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // roles can't be granted directly either
+    await program.methods
+      .grantRole({
+        role: { operator: {} },
+        account: newOperator,
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // the queued change is not executable before the delay
+    changeId = await queueChange({ updateOperators: { operators: [newOperator] } });
+    await executeChange(changeId)
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // the queued change can be cancelled
+    await program.methods
+      .cancelAdminChange({ changeId })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.queuedChanges.length).eq(0);
+
+    // disable the timelock again once the delay passed
+    changeId = await queueChange({ updateTimelockDelay: { timelockDelay: new anchor.BN(0) } });
+    await sleep(3000);
+    await executeChange(changeId).catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.timelockDelay.eq(new anchor.BN(0))).to.be.true;
+  });
+
+  it("[multisig] should: admin actions are executed once the multisig threshold is met", async () => {
+    const coSigner = Keypair.generate();
+
+    const getProposalAccount = (proposalId: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("SEED::POCKET::MULTISIG_PROPOSAL_SEED"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+
+    const propose = async (action: any) => {
+      const state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+      const multisigProposal = getProposalAccount(state.nextProposalId);
+
+      await program.methods
+        .proposeMultisigAction({ action })
+        .accounts({
+          signer: deployer.publicKey,
+          pocketRegistry,
+          multisigProposal,
+        })
+        .signers([deployer.payer])
+        .rpc({ commitment: "confirmed" })
+        .catch((e) => console.log(e));
+
+      return multisigProposal;
+    };
+
+    const approve = (multisigProposal: PublicKey) =>
+      program.methods
+        .approveMultisigAction()
+        .accounts({
+          signer: coSigner.publicKey,
+          pocketRegistry,
+          multisigProposal,
+        })
+        .signers([coSigner])
+        .rpc({ commitment: "confirmed" });
+
+    const execute = (multisigProposal: PublicKey) =>
+      program.methods
+        .executeMultisigAction()
+        .accounts({
+          signer: deployer.publicKey,
+          pocketRegistry,
+          multisigProposal,
+          proposer: deployer.publicKey,
+        })
+        .signers([deployer.payer])
+        .rpc({ commitment: "confirmed" });
+
+    // enable the 2-of-2 multisig
+    await program.methods
+      .queueAdminChange({
+        action: {
+          updateMultisig: {
+            signers: [deployer.publicKey, coSigner.publicKey],
+            threshold: 2,
+          },
+        },
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    let state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    await program.methods
+      .executeAdminChange({ changeId: state.nextChangeId.subn(1) })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.multisigThreshold).eq(2);
+
+    // single key pause is rejected
+    await program.methods
+      .pausePlatform()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // single key role changes are rejected
+    await program.methods
+      .grantRole({
+        role: { pauser: {} },
+        account: coSigner.publicKey,
+      })
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" })
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    // the proposal can't be executed before the threshold is met
+    let multisigProposal = await propose({ setPaused: { isPaused: true } });
+    await execute(multisigProposal)
+      .then(() => {
+        throw new Error("ShouldFailed");
+      })
+      .catch((e) => expect(e.toString().includes("ShouldFailed")).to.be.false);
+
+    await approve(multisigProposal).catch((e) => console.log(e));
+    await execute(multisigProposal).catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.isPaused).to.be.true;
+    expect(await provider.connection.getAccountInfo(multisigProposal)).to.be.null;
+
+    // unpause and disable the multisig again
+    multisigProposal = await propose({ setPaused: { isPaused: false } });
+    await approve(multisigProposal).catch((e) => console.log(e));
+    await execute(multisigProposal).catch((e) => console.log(e));
+
+    multisigProposal = await propose({ updateMultisig: { signers: [], threshold: 0 } });
+    await approve(multisigProposal).catch((e) => console.log(e));
+    await execute(multisigProposal).catch((e) => console.log(e));
+
+    state = await program.account.pocketPlatformRegistry.fetch(pocketRegistry);
+    expect(state.isPaused).to.be.false;
+    expect(state.multisigThreshold).eq(0);
+  });
 });
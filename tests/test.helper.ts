@@ -1,14 +1,117 @@
 import * as anchor from "@project-serum/anchor";
-import { Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  Keypair,
+  PublicKey,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction
+} from "@solana/web3.js";
 
 import {
+  ACCOUNT_SIZE,
+  TOKEN_PROGRAM_ID,
+  createInitializeAccountInstruction,
   createMint, getOrCreateAssociatedTokenAccount,
   mintTo
 } from "@solana/spl-token";
+import { DexInstructions, Market } from "@openbook-dex/openbook";
 import { AnchorProvider } from "@project-serum/anchor/dist/cjs/provider";
 
 import { IDL } from "../target/types/pocket";
 
+export const DEX_PROGRAM_ID = new PublicKey(process.env.DEX_PROGRAM_ID);
+
+/**
+ * @dev List a new market on the dex for the given token pair
+ */
+export const createMarket = async (
+  provider: AnchorProvider,
+  baseMint: PublicKey,
+  quoteMint: PublicKey
+) => {
+  const payer = (provider.wallet as anchor.Wallet).payer;
+
+  const market = Keypair.generate();
+  const requestQueue = Keypair.generate();
+  const eventQueue = Keypair.generate();
+  const bids = Keypair.generate();
+  const asks = Keypair.generate();
+  const baseVault = Keypair.generate();
+  const quoteVault = Keypair.generate();
+
+  // find the vault signer nonce
+  let vaultSignerNonce = new anchor.BN(0);
+  let vaultOwner: PublicKey;
+  while (true) {
+    try {
+      vaultOwner = PublicKey.createProgramAddressSync(
+        [market.publicKey.toBuffer(), vaultSignerNonce.toArrayLike(Buffer, "le", 8)],
+        DEX_PROGRAM_ID
+      );
+      break;
+    } catch (e) {
+      vaultSignerNonce = vaultSignerNonce.addn(1);
+    }
+  }
+
+  const createAccount = async (account: Keypair, space: number, programId: PublicKey) =>
+    SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: account.publicKey,
+      lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+      space,
+      programId,
+    });
+
+  // create market vaults
+  await provider.sendAndConfirm(
+    new Transaction().add(
+      await createAccount(baseVault, ACCOUNT_SIZE, TOKEN_PROGRAM_ID),
+      await createAccount(quoteVault, ACCOUNT_SIZE, TOKEN_PROGRAM_ID),
+      createInitializeAccountInstruction(baseVault.publicKey, baseMint, vaultOwner),
+      createInitializeAccountInstruction(quoteVault.publicKey, quoteMint, vaultOwner)
+    ),
+    [payer, baseVault, quoteVault],
+    { commitment: "confirmed" }
+  );
+
+  // create market and queues
+  await provider.sendAndConfirm(
+    new Transaction().add(
+      await createAccount(market, Market.getLayout(DEX_PROGRAM_ID).span, DEX_PROGRAM_ID),
+      await createAccount(requestQueue, 5120 + 12, DEX_PROGRAM_ID),
+      await createAccount(eventQueue, 262144 + 12, DEX_PROGRAM_ID),
+      await createAccount(bids, 65536 + 12, DEX_PROGRAM_ID),
+      await createAccount(asks, 65536 + 12, DEX_PROGRAM_ID),
+      DexInstructions.initializeMarket({
+        market: market.publicKey,
+        requestQueue: requestQueue.publicKey,
+        eventQueue: eventQueue.publicKey,
+        bids: bids.publicKey,
+        asks: asks.publicKey,
+        baseVault: baseVault.publicKey,
+        quoteVault: quoteVault.publicKey,
+        baseMint,
+        quoteMint,
+        baseLotSize: new anchor.BN(1_000_000),
+        quoteLotSize: new anchor.BN(10_000),
+        feeRateBps: 0,
+        vaultSignerNonce,
+        quoteDustThreshold: new anchor.BN(100),
+        programId: DEX_PROGRAM_ID,
+      })
+    ),
+    [payer, market, requestQueue, eventQueue, bids, asks],
+    { commitment: "confirmed" }
+  );
+
+  return {
+    market: market.publicKey,
+    bids: bids.publicKey,
+    asks: asks.publicKey,
+  };
+};
+
 export const getFixtures = async (provider: AnchorProvider) => {
   // Configure the client to use the local cluster.
   anchor.setProvider(provider);
@@ -24,19 +127,20 @@ export const getFixtures = async (provider: AnchorProvider) => {
     program.programId
   );
 
-  // find the pocket account
+  let nonOwner: Keypair = Keypair.generate();
+  let owner: Keypair = Keypair.generate();
+  let operator: Keypair = Keypair.generate();
+
+  // find the pocket account, seeded by the owner and the id
   const [pocketAccount] = PublicKey.findProgramAddressSync(
     [
       anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_SEED"),
+      owner.publicKey.toBytes(),
       anchor.utils.bytes.utf8.encode(pocketId)
     ],
     program.programId
   );
 
-  let nonOwner: Keypair = Keypair.generate();
-  let owner: Keypair = Keypair.generate();
-  let operator: Keypair = Keypair.generate();
-
   // Funding signer accounts
   await provider.connection.requestAirdrop(
     operator.publicKey,
@@ -124,6 +228,50 @@ export const getFixtures = async (provider: AnchorProvider) => {
     LAMPORTS_PER_SOL * 100
   );
 
+  // whitelist mint tokens
+  for (const mintAccount of [baseMintAccount, targetMintAccount]) {
+    await program.methods
+      .addMintAccount()
+      .accounts({
+        signer: deployer.publicKey,
+        pocketRegistry,
+        mintAccount,
+      })
+      .signers([deployer.payer])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  // whitelist the market of the token pair
+  const {
+    market: marketAccount,
+    bids: marketBids,
+    asks: marketAsks,
+  } = await createMarket(
+    provider,
+    baseMintAccount,
+    targetMintAccount
+  );
+
+  await program.methods
+    .addMarket()
+    .accounts({
+      signer: deployer.publicKey,
+      pocketRegistry,
+      marketKey: marketAccount,
+      dexProgram: DEX_PROGRAM_ID,
+    })
+    .signers([deployer.payer])
+    .rpc({ commitment: "confirmed" });
+
+  // find the pocket index of the owner
+  const [ownerPocketIndex] = PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("SEED::POCKET::POCKET_OWNER_INDEX_SEED"),
+      owner.publicKey.toBytes()
+    ],
+    program.programId
+  );
+
   const [baseMintVaultAccount] = PublicKey.findProgramAddressSync(
     [
       anchor.utils.bytes.utf8.encode("SEED::POCKET::TOKEN_VAULT_SEED"),
@@ -151,6 +299,7 @@ export const getFixtures = async (provider: AnchorProvider) => {
     pocketId,
     operator,
     owner,
+    ownerPocketIndex,
     ownerBaseTokenAccount,
     ownerTargetTokenAccount,
     nonOwner,
@@ -158,6 +307,9 @@ export const getFixtures = async (provider: AnchorProvider) => {
     nonOwnerTargetTokenAccount,
     baseMintAccount,
     targetMintAccount,
+    marketAccount,
+    marketBids,
+    marketAsks,
     baseMintVaultAccount,
     targetMintVaultAccount
   };